@@ -0,0 +1,31 @@
+//! Turns `examples/answers.toml` into a test per known answer, once through
+//! the host solvers and once through the no_std ones the firmware runs, and
+//! builds each day's first example into `aoc` for `input --c`.
+
+use std::fmt::Write;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=../examples");
+
+    let mut tests = String::new();
+    aoc_examples::generate_tests(&mut tests, "check_host", "", |_| true);
+    aoc_examples::generate_tests(&mut tests, "check_no_std", "_no_std", |day| {
+        aoc_firmware::DAYS.contains(&day)
+    });
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("examples.rs"), tests).unwrap();
+
+    let mut days = Vec::new();
+    let mut first = String::from("match day {\n");
+    for example in aoc_examples::load() {
+        if !days.contains(&example.day) {
+            days.push(example.day);
+            let path = example.path();
+            writeln!(first, "    {} => include_str!({path:?}),", example.day).unwrap();
+        }
+    }
+    first.push_str("    _ => \"\",\n}\n");
+    std::fs::write(Path::new(&out_dir).join("first_example.rs"), first).unwrap();
+}
@@ -0,0 +1,219 @@
+//! Timing each day's parse and parts, and spotting when one gets slower.
+
+use aoc_core::timing::{Clock, Timings};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::Instant;
+
+/// Steps faster than this are too noisy to call a regression either way.
+pub const NOISE_US: u64 = 100;
+
+/// How long one solver took over one input, as the median of a few runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    /// `host`, or `no_std` for the firmware's fixed capacity code.
+    pub solver: String,
+    /// A path for a real input, or how a generated one was made.
+    pub input: String,
+    pub parse_us: u64,
+    pub part1_us: u64,
+    pub part2_us: u64,
+}
+
+impl Measurement {
+    pub fn new(day: u8, solver: &str, input: &str, timings: Timings) -> Self {
+        Self {
+            day,
+            solver: solver.to_owned(),
+            input: input.to_owned(),
+            parse_us: timings.parse_us,
+            part1_us: timings.part1_us,
+            part2_us: timings.part2_us,
+        }
+    }
+
+    fn steps(&self) -> [(&'static str, u64); 3] {
+        [
+            ("parse", self.parse_us),
+            ("part 1", self.part1_us),
+            ("part 2", self.part2_us),
+        ]
+    }
+
+    /// The input without its directory, so a baseline saved in another
+    /// checkout or from another inputs directory still lines up.
+    fn input_name(&self) -> Option<&OsStr> {
+        Path::new(&self.input).file_name()
+    }
+
+    fn same_run(&self, other: &Self) -> bool {
+        (self.day, &self.solver, self.input_name())
+            == (other.day, &other.solver, other.input_name())
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:2} {:6} {:>10}us {:>10}us {:>10}us  {}",
+            self.day, self.solver, self.parse_us, self.part1_us, self.part2_us, self.input
+        )
+    }
+}
+
+/// Runs `solve` `runs` times against the wall clock and keeps the median
+/// of each step, or the first error.
+pub fn median<E>(
+    runs: usize,
+    mut solve: impl FnMut(&dyn Clock) -> Result<Timings, E>,
+) -> Result<Timings, E> {
+    let start = Instant::now();
+    let clock = || start.elapsed().as_micros() as u64;
+
+    let mut all = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        all.push(solve(&clock)?);
+    }
+
+    let mut middle = |step: fn(&Timings) -> u64| {
+        all.sort_by_key(step);
+        step(&all[all.len() / 2])
+    };
+    Ok(Timings {
+        parse_us: middle(|timings| timings.parse_us),
+        part1_us: middle(|timings| timings.part1_us),
+        part2_us: middle(|timings| timings.part2_us),
+    })
+}
+
+/// Runs `time`, turning a panic into an error so that one broken day
+/// doesn't stop the rest being timed. The panic still prints as usual.
+pub fn caught<T>(time: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(time)).unwrap_or_else(|_| Err("panicked".to_owned()))
+}
+
+/// A step that took longer than the baseline allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub day: u8,
+    pub solver: String,
+    pub input: String,
+    pub step: &'static str,
+    pub before_us: u64,
+    pub after_us: u64,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} {} {} on {}: {}us -> {}us",
+            self.day, self.solver, self.step, self.input, self.before_us, self.after_us
+        )
+    }
+}
+
+/// Every step in `current` more than `tolerance` percent slower than the
+/// same step in `baseline`. Runs that only one side has are skipped.
+pub fn regressions(
+    baseline: &[Measurement],
+    current: &[Measurement],
+    tolerance: u64,
+) -> Vec<Regression> {
+    let mut found = Vec::new();
+
+    for now in current {
+        let Some(before) = baseline.iter().find(|before| before.same_run(now)) else {
+            continue;
+        };
+
+        for ((step, before_us), (_, after_us)) in before.steps().into_iter().zip(now.steps()) {
+            if after_us >= before_us + NOISE_US && after_us * 100 > before_us * (100 + tolerance) {
+                found.push(Regression {
+                    day: now.day,
+                    solver: now.solver.clone(),
+                    input: now.input.clone(),
+                    step,
+                    before_us,
+                    after_us,
+                });
+            }
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measured(parse_us: u64, part1_us: u64, part2_us: u64) -> Measurement {
+        let timings = Timings {
+            parse_us,
+            part1_us,
+            part2_us,
+        };
+        Measurement::new(12, "host", "day12.txt", timings)
+    }
+
+    #[test]
+    fn test_median() {
+        let mut runs = [300, 100, 200].into_iter();
+        let timings = median(3, |_| {
+            let part1_us = runs.next().unwrap();
+            Ok::<_, ()>(Timings {
+                part1_us,
+                ..Timings::default()
+            })
+        });
+        assert_eq!(timings.unwrap().part1_us, 200);
+        assert_eq!(median(3, |_| Err("broken")), Err("broken"));
+    }
+
+    #[test]
+    fn test_caught() {
+        assert_eq!(caught(|| Ok(1)), Ok(1));
+        assert_eq!(
+            caught::<()>(|| panic!("broken")),
+            Err("panicked".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = [measured(10, 1_000, 5_000)];
+
+        // a tiny step doubling is noise, and 10% is within tolerance
+        assert!(regressions(&baseline, &[measured(20, 1_000, 5_500)], 20).is_empty());
+
+        let found = regressions(&baseline, &[measured(10, 1_500, 4_000)], 20);
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].to_string(),
+            "day 12 host part 1 on day12.txt: 1000us -> 1500us"
+        );
+
+        let mut elsewhere = measured(10, 9_000, 9_000);
+        elsewhere.input = "generated".to_owned();
+        assert!(regressions(&baseline, &[elsewhere], 20).is_empty());
+
+        // the same input read from another directory
+        let mut moved = measured(10, 1_500, 5_000);
+        moved.input = "/elsewhere/inputs/day12.txt".to_owned();
+        assert_eq!(regressions(&baseline, &[moved], 20).len(), 1);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let measurements = vec![measured(1, 2, 3)];
+        let json = serde_json::to_string(&measurements).unwrap();
+        let back: Vec<Measurement> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, measurements);
+    }
+}
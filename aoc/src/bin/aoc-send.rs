@@ -0,0 +1,127 @@
+//! Hands a puzzle input to the Pico over USB serial and prints its answers.
+
+use aoc_core::Part;
+use aoc_core::protocol::{Decoder, FrameError, Header, Kind};
+use clap::Parser;
+use nix::sys::termios::{SetArg, cfmakeraw, tcgetattr, tcsetattr};
+use std::fs::{File, OpenOptions};
+use std::io::{self, IsTerminal, Read, Write};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Room for the longest answer or error message the Pico sends back.
+const MAX_REPLY: usize = 256;
+
+#[derive(Parser)]
+#[command(about = "Solve a day on the Pico over USB serial")]
+struct Cli {
+    /// Serial device the Pico shows up as, e.g. /dev/ttyACM0
+    #[arg(long)]
+    device: PathBuf,
+    #[arg(long)]
+    day: u8,
+    /// Only solve this part, otherwise both are solved
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    #[arg(long)]
+    input: PathBuf,
+}
+
+fn open(device: &Path) -> io::Result<File> {
+    let port = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .custom_flags(nix::libc::O_NOCTTY)
+        .open(device)?;
+
+    // the tty line discipline would otherwise echo and mangle the binary frames
+    if port.is_terminal() {
+        let mut termios = tcgetattr(&port)?;
+        cfmakeraw(&mut termios);
+        tcsetattr(&port, SetArg::TCSANOW, &termios)?;
+    }
+
+    Ok(port)
+}
+
+fn send(port: &mut File, day: u8, part: Option<Part>, input: &[u8]) -> io::Result<()> {
+    let header = Header::new(Kind::Input, day, part, input);
+    port.write_all(&header.encode())?;
+    port.write_all(input)?;
+    port.flush()
+}
+
+fn receive(port: &mut File, answers: usize) -> Result<Vec<(Part, String)>, String> {
+    let mut buf = [0; MAX_REPLY];
+    let mut decoder = Decoder::new(&mut buf);
+    let mut received = Vec::new();
+    let mut chunk = [0; 64];
+
+    while received.len() < answers {
+        let count = port.read(&mut chunk).map_err(|err| err.to_string())?;
+        if count == 0 {
+            return Err("the device hung up".to_owned());
+        }
+
+        for &byte in &chunk[..count] {
+            let header = match decoder.push(byte) {
+                Ok(Some(header)) => header,
+                Ok(None) => continue,
+                // stray bytes between frames, e.g. boot noise from the Pico;
+                // the decoder picks up again at the next frame start
+                Err(FrameError::UnknownKind(_)) => continue,
+                Err(err) => return Err(err.to_string()),
+            };
+            let text = String::from_utf8_lossy(decoder.payload()).into_owned();
+
+            match (header.kind, header.part) {
+                (Kind::Answer, Some(part)) => received.push((part, text)),
+                (Kind::Error, _) => return Err(text),
+                _ => return Err(format!("unexpected {:?} frame", header.kind)),
+            }
+        }
+    }
+
+    Ok(received)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let input = match std::fs::read(&cli.input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("could not read {}: {err}", cli.input.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut port = match open(&cli.device) {
+        Ok(port) => port,
+        Err(err) => {
+            eprintln!("could not open {}: {err}", cli.device.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let part = cli.part.and_then(Part::new);
+    if let Err(err) = send(&mut port, cli.day, part, &input) {
+        eprintln!("could not send the input: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    let answers = if part.is_some() { 1 } else { 2 };
+    match receive(&mut port, answers) {
+        Ok(received) => {
+            for (part, answer) in received {
+                println!("day {} part {part}: {answer}", cli.day);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("day {}: {err}", cli.day);
+            ExitCode::FAILURE
+        }
+    }
+}
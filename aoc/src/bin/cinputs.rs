@@ -0,0 +1,72 @@
+//! Writes the `inputs.h` the C projects include, from the puzzle input and
+//! an example next to it.
+
+use aoc_embed::{Header, Style};
+use clap::Parser;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Turn puzzle inputs into a C header")]
+struct Cli {
+    /// Becomes `input`
+    #[arg(long, default_value = "input.txt")]
+    input: PathBuf,
+    /// Becomes `example1`, and is left empty if there's no such file
+    #[arg(long, default_value = "example1.txt")]
+    example: PathBuf,
+    /// Byte arrays rather than string literals
+    #[arg(long)]
+    bytes: bool,
+    /// A table of where each line starts in each input
+    #[arg(long)]
+    lines: bool,
+    #[arg(long, short, default_value = "inputs.h")]
+    out: PathBuf,
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let input = match read(&cli.input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let example = if cli.example.exists() {
+        read(&cli.example)
+    } else {
+        Ok(String::new())
+    };
+    let example = match example {
+        Ok(example) => example,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let style = if cli.bytes {
+        Style::Bytes
+    } else {
+        Style::String
+    };
+    let mut header = Header::new(style)
+        .array("input", &input)
+        .array("example1", &example);
+    if cli.lines {
+        header = header.lines();
+    }
+
+    if let Err(err) = std::fs::write(&cli.out, header.to_string()) {
+        eprintln!("could not write {}: {err}", cli.out.display());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
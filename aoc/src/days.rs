@@ -0,0 +1,199 @@
+use aoc_core::error::InputError;
+use aoc_core::{Puzzle, Setup, Solver};
+use aoc_vis::Frame;
+use std::fmt;
+
+/// A setting a day can't be run with, like a room with no width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadSetup {
+    pub day: u8,
+    pub setting: &'static str,
+    pub value: usize,
+    /// The most it can be, the least always being 1.
+    pub max: usize,
+}
+
+impl fmt::Display for BadSetup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}'s {} has to be 1 to {}, not {}",
+            self.day, self.setting, self.max, self.value
+        )
+    }
+}
+
+impl std::error::Error for BadSetup {}
+
+#[derive(Debug)]
+pub enum Error {
+    Setup(BadSetup),
+    Input(InputError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Setup(err) => write!(f, "{err}"),
+            Error::Input(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<BadSetup> for Error {
+    fn from(err: BadSetup) -> Self {
+        Error::Setup(err)
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(err: InputError) -> Self {
+        Error::Input(err)
+    }
+}
+
+pub fn puzzle(day: u8) -> Option<Box<dyn Puzzle>> {
+    // every day's defaults are its real puzzle's, which fit
+    puzzle_with(day, &Setup::default()).map(|puzzle| puzzle.unwrap())
+}
+
+/// The day as set up for an input other than the real puzzle's, like one
+/// of the examples.
+pub fn puzzle_with(day: u8, setup: &Setup) -> Option<Result<Box<dyn Puzzle>, BadSetup>> {
+    let puzzle: Box<dyn Puzzle> = match day {
+        1 => Box::new(r01_host::Day01),
+        2 => Box::new(r02_host::Day02),
+        3 => Box::new(r03_host::Day03),
+        4 => Box::new(r04_host::Day04),
+        5 => Box::new(r05_host::Day05),
+        7 => Box::new(r07_host::Day07),
+        8 => Box::new(r08_host::Day08),
+        9 => Box::new(r09_host::Day09),
+        10 => Box::new(r10_host::Day10),
+        11 => Box::new(r11_host::Day11),
+        12 => Box::new(r12_host::Day12),
+        13 => Box::new(r13_host::Day13),
+        14 => match day14(setup) {
+            Ok(day14) => Box::new(day14),
+            Err(err) => return Some(Err(err)),
+        },
+        15 => Box::new(r15_host::Day15),
+        16 => Box::new(r16_host::Day16),
+        17 => Box::new(r17_host::Day17),
+        18 => Box::new(day18(setup)),
+        19 => Box::new(r19_host::Day19),
+        20 => Box::new(day20_host::Day20 {
+            saving: setup.saving.unwrap_or(day20_host::SAVING),
+        }),
+        21 => Box::new(r21_host::Day21),
+        22 => Box::new(r22_host::Day22),
+        23 => Box::new(r23_host::Day23),
+        24 => Box::new(r24_host::Day24),
+        25 => Box::new(r25_host::Day25),
+        _ => return None,
+    };
+
+    Some(Ok(puzzle))
+}
+
+fn day14(setup: &Setup) -> Result<r14_host::Day14, BadSetup> {
+    let (width, height) = r14_host::DIMENSIONS;
+    // the robots wrap around the room's edges, so it needs some of both
+    let side = |setting, side: Option<usize>, default| match side {
+        None => Ok(default),
+        Some(value) => i16::try_from(value)
+            .ok()
+            .filter(|&side| side > 0)
+            .ok_or(BadSetup {
+                day: 14,
+                setting,
+                value,
+                max: i16::MAX as usize,
+            }),
+    };
+    Ok(r14_host::Day14 {
+        dimensions: (
+            side("width", setup.width, width)?,
+            side("height", setup.height, height)?,
+        ),
+    })
+}
+
+fn day18(setup: &Setup) -> r18_host::Day18 {
+    r18_host::Day18 {
+        width: setup.width.unwrap_or(r18_host::SIZE),
+        height: setup.height.unwrap_or(r18_host::SIZE),
+        drops: setup.drops.unwrap_or(r18_host::DROPS),
+    }
+}
+
+type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// The frames a simulation day draws of itself running on `input`, for the
+/// days that have any.
+pub fn frames(day: u8, input: &str, setup: &Setup) -> Option<Result<Frames, Error>> {
+    fn boxed(frames: impl Iterator<Item = Frame> + 'static) -> Frames {
+        Box::new(frames)
+    }
+
+    let frames = match day {
+        14 => match day14(setup) {
+            Ok(day14) => day14.parse(input).map(|room| boxed(r14_host::frames(room))),
+            Err(err) => return Some(Err(err.into())),
+        },
+        15 => r15_host::Day15
+            .parse(input)
+            .map(|maps| boxed(r15_host::frames(maps))),
+        16 => r16_host::Day16
+            .parse(input)
+            .map(|maze| boxed(r16_host::frames(maze))),
+        18 => day18(setup)
+            .parse(input)
+            .map(|mb| boxed(r18_host::frames(mb))),
+        _ => return None,
+    };
+
+    Some(frames.map_err(Error::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day14_setup() {
+        let room = |width, height| {
+            let setup = Setup {
+                width,
+                height,
+                ..Setup::default()
+            };
+            day14(&setup).map(|day14| day14.dimensions)
+        };
+
+        assert_eq!(room(None, None), Ok(r14_host::DIMENSIONS));
+        assert_eq!(room(Some(11), Some(7)), Ok((11, 7)));
+        assert_eq!(room(Some(32767), None), Ok((32767, 103)));
+        let bad = |setting, value| BadSetup {
+            day: 14,
+            setting,
+            value,
+            max: 32767,
+        };
+        assert_eq!(room(Some(0), None), Err(bad("width", 0)));
+        assert_eq!(room(Some(11), Some(0)), Err(bad("height", 0)));
+        assert_eq!(room(Some(65547), Some(7)), Err(bad("width", 65547)));
+        assert!(
+            frames(
+                14,
+                "",
+                &Setup {
+                    width: Some(0),
+                    ..Setup::default()
+                }
+            )
+            .unwrap()
+            .is_err_and(|err| matches!(err, Error::Setup(_)))
+        );
+    }
+}
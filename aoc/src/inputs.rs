@@ -0,0 +1,171 @@
+//! Puzzle inputs, downloaded once and kept in a cache from then on.
+//!
+//! Each account gets its own inputs, so the cache keeps them apart by a hash
+//! of the session cookie rather than the cookie itself:
+//!
+//! ```text
+//! ~/.cache/aoc/<session hash>/2024/day01.txt
+//! ```
+//!
+//! Inputs copied in or looked up without a session cookie go under `local`
+//! in place of the hash.
+//!
+//! From there an input can be written out where a build expects it: the
+//! `input.txt` a firmware crate `include_str!`s, or the `inputs.h` a C
+//! project `#include`s.
+
+use crate::site::{Client, YEAR};
+use crate::store::input_hash;
+use aoc_embed::normalise;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Why an input doesn't look like the site served it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Malformed {
+    Empty,
+    /// Windows line endings, which the solvers don't expect.
+    CarriageReturns,
+    /// How many newlines it ends with, when it should be exactly one.
+    Ending(usize),
+}
+
+impl fmt::Display for Malformed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Malformed::Empty => write!(f, "the input is empty"),
+            Malformed::CarriageReturns => write!(f, "the input has \\r\\n line endings"),
+            Malformed::Ending(newlines) => {
+                write!(f, "the input ends in {newlines} newlines rather than one")
+            }
+        }
+    }
+}
+
+/// Checks `input` ends its lines the way the site does: `\n`, with exactly
+/// one after the last line. `normalise` fixes anything else it complains
+/// about, for inputs copied in by hand.
+pub fn validate(input: &str) -> Result<(), Malformed> {
+    if input.trim().is_empty() {
+        return Err(Malformed::Empty);
+    }
+    if input.contains('\r') {
+        return Err(Malformed::CarriageReturns);
+    }
+    match input.len() - input.trim_end_matches('\n').len() {
+        1 => Ok(()),
+        newlines => Err(Malformed::Ending(newlines)),
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Http(Box<ureq::Error>),
+    Malformed(PathBuf, Malformed),
+    /// Not cached, and fetching it wasn't allowed.
+    NotCached(u8),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Http(err) => write!(f, "could not fetch the input: {err}"),
+            Error::Malformed(path, malformed) => write!(f, "{}: {malformed}", path.display()),
+            Error::NotCached(day) => write!(f, "day {day}'s input isn't cached"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Where the cache goes unless told otherwise, following the XDG spec.
+pub fn default_cache() -> PathBuf {
+    match std::env::var_os("XDG_CACHE_HOME") {
+        Some(dir) => PathBuf::from(dir).join("aoc"),
+        None => PathBuf::from(std::env::var_os("HOME").unwrap_or_default()).join(".cache/aoc"),
+    }
+}
+
+pub struct Cache {
+    /// This session's part of the cache.
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(root: &Path, session: &str) -> Self {
+        Self {
+            dir: root.join(input_hash(session)).join(YEAR.to_string()),
+        }
+    }
+
+    /// The part of the cache for when there's no session, which can only
+    /// ever hold inputs copied in by hand.
+    pub fn local(root: &Path) -> Self {
+        Self {
+            dir: root.join("local").join(YEAR.to_string()),
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day:02}.txt"))
+    }
+
+    /// The cached input for `day`, if there is one.
+    pub fn get(&self, day: u8) -> Result<Option<String>, Error> {
+        let path = self.path(day);
+        let input = match std::fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        validate(&input).map_err(|malformed| Error::Malformed(path, malformed))?;
+        Ok(Some(input))
+    }
+
+    /// Adds an input to the cache, normalised.
+    pub fn put(&self, day: u8, input: &str) -> Result<String, Error> {
+        let input = normalise(input);
+        validate(&input).map_err(|malformed| Error::Malformed(self.path(day), malformed))?;
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(day), &input)?;
+        Ok(input)
+    }
+
+    /// The input for `day`, from the cache if it's there and otherwise
+    /// downloaded with `client` and cached. With no client it has to be
+    /// cached already.
+    pub fn fetch(&self, day: u8, client: Option<&Client>) -> Result<String, Error> {
+        if let Some(input) = self.get(day)? {
+            return Ok(input);
+        }
+        let Some(client) = client else {
+            return Err(Error::NotCached(day));
+        };
+
+        let input = client.input(day).map_err(Error::Http)?;
+        // the site's own inputs should never need fixing
+        validate(&input).map_err(|malformed| Error::Malformed(self.path(day), malformed))?;
+        self.put(day, &input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("3   4\n4   3\n"), Ok(()));
+        assert_eq!(validate("3   4\n4   3"), Err(Malformed::Ending(0)));
+        assert_eq!(validate("3   4\n\n\n"), Err(Malformed::Ending(3)));
+        assert_eq!(validate("3   4\r\n"), Err(Malformed::CarriageReturns));
+        assert_eq!(validate("\n"), Err(Malformed::Empty));
+    }
+}
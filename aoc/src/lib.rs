@@ -0,0 +1,5 @@
+pub mod bench;
+pub mod days;
+pub mod inputs;
+pub mod site;
+pub mod store;
@@ -0,0 +1,858 @@
+use aoc::bench::{self, Measurement};
+use aoc::days;
+use aoc::inputs::{self, Cache};
+use aoc::site::{self, Client, Outcome, Response};
+use aoc::store::{self, Store};
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::report::Report;
+use aoc_core::rng::Rng;
+use aoc_core::{Part, Setup, Solver};
+use aoc_embed::{Header, Style};
+use aoc_gen::Params;
+use aoc_vis::Format;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use log::{LevelFilter, Log, Metadata, Record};
+use r01_host::Day01;
+use r01_host::report::{self, Row};
+use r02_host::Rules;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+struct Cli {
+    /// Show what the solvers log along the way, more for each -v
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Output {
+    /// `day 1 part 1: 11`, a line per part
+    Text,
+    /// One JSON object per day, for scripts
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Table {
+    /// Each pair of sorted numbers and how far apart they are
+    Pairs,
+    /// Only the pairs furthest apart
+    Gaps,
+    /// How often each right hand number turns up, and its similarity score
+    Counts,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Sheet {
+    /// With a header line
+    Csv,
+    /// An array of objects
+    Json,
+}
+
+/// `rows` in the chosen format.
+fn sheet<R: Row>(rows: &[R], format: Sheet) -> String {
+    match format {
+        Sheet::Csv => report::csv(rows),
+        Sheet::Json => format!("{}\n", serde_json::to_string(rows).unwrap()),
+    }
+}
+
+/// What a few days need to know that their input doesn't say, for running
+/// them on something other than a real input, like the puzzle examples.
+/// Anything left out is the real puzzle's.
+#[derive(Args)]
+struct SetupArgs {
+    /// Tiles across day 14's room or day 18's memory space
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..=i16::MAX as i64))]
+    width: Option<u16>,
+    /// Tiles down day 14's room or day 18's memory space
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..=i16::MAX as i64))]
+    height: Option<u16>,
+    /// Bytes fallen into day 18's memory space before part 1 looks
+    #[arg(long)]
+    drops: Option<usize>,
+    /// The fewest picoseconds a day 20 cheat has to save to count
+    #[arg(long)]
+    saving: Option<usize>,
+}
+
+impl SetupArgs {
+    fn setup(&self) -> Setup {
+        Setup {
+            width: self.width.map(usize::from),
+            height: self.height.map(usize::from),
+            drops: self.drops,
+            saving: self.saving,
+        }
+    }
+}
+
+/// A day's answers as `--format json` prints them.
+#[derive(Serialize)]
+struct Solved {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+}
+
+/// Whatever the solvers log, on stderr so it never mixes with the answers.
+struct Stderr;
+
+impl Log for Stderr {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day against a puzzle input
+    Run {
+        #[arg(long)]
+        day: u8,
+        /// Only solve this part, otherwise both are solved
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[arg(long)]
+        input: PathBuf,
+        #[arg(long, value_enum, default_value_t = Output::Text)]
+        format: Output,
+        #[command(flatten)]
+        setup: SetupArgs,
+    },
+    /// Print a day's input, downloading it into the cache the first time.
+    /// Downloading needs the session cookie in AOC_SESSION.
+    Input {
+        #[arg(long)]
+        day: u8,
+        /// Where inputs are cached, otherwise ~/.cache/aoc
+        #[arg(long)]
+        cache: Option<PathBuf>,
+        /// Only use what's cached, never the network
+        #[arg(long)]
+        offline: bool,
+        /// Cache this hand-copied input rather than downloading one
+        #[arg(long, conflicts_with = "offline")]
+        import: Option<PathBuf>,
+        /// Write it to input.txt in this firmware crate instead of printing it
+        #[arg(long)]
+        rust: Option<PathBuf>,
+        /// Write it to inputs.h in this C project instead of printing it
+        #[arg(long)]
+        c: Option<PathBuf>,
+        #[arg(long, default_value = site::SITE)]
+        site: String,
+    },
+    /// Check each day's answers against those known to be right
+    Verify {
+        /// Directory of inputs named like day01.txt, any missing are skipped
+        #[arg(long)]
+        inputs: PathBuf,
+        /// Only this day, otherwise every day
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long, default_value = "answers.toml")]
+        store: PathBuf,
+    },
+    /// Send an answer to the site, unless it's already known to be wrong.
+    /// Needs the session cookie in AOC_SESSION.
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long)]
+        input: PathBuf,
+        #[arg(long, default_value = "answers.toml")]
+        store: PathBuf,
+        #[arg(long, default_value = site::SITE)]
+        site: String,
+    },
+    /// Pull the answers out of a firmware log captured with defmt-print or probe-rs
+    ParseLog {
+        /// Log file to read, otherwise stdin
+        log: Option<PathBuf>,
+    },
+    /// Make up a puzzle input and print it
+    Gen {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Lines, or cells a side for the maps, otherwise about as big as a real input
+        #[arg(long)]
+        size: Option<usize>,
+        /// Pairs of wires to swap in the day 24 adder
+        #[arg(long, default_value_t = 4)]
+        swaps: usize,
+    },
+    /// Watch a simulation day run, or write out its frames
+    Vis {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        input: PathBuf,
+        /// Frames a second
+        #[arg(long, default_value_t = 20)]
+        fps: u32,
+        /// Write each frame to this directory instead of playing them
+        #[arg(long)]
+        export: Option<PathBuf>,
+        /// text, ppm or png
+        #[arg(long, default_value = "text")]
+        format: Format,
+        /// Pixels a side for each cell in the images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        #[command(flatten)]
+        setup: SetupArgs,
+    },
+    /// Time each day's parse and parts on real and generated inputs
+    Bench {
+        /// Only this day, otherwise every day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Directory of real inputs named like day01.txt, any missing are skipped
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the generated inputs, otherwise about as big as a real input
+        #[arg(long)]
+        size: Option<usize>,
+        /// Runs of each input, of which the median is kept
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+        /// Write the timings here as JSON
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Timings saved earlier to compare against, failing on any regression
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How much slower than the baseline a step may get, in percent
+        #[arg(long, default_value_t = 20)]
+        tolerance: u64,
+    },
+    /// Show how the day 1 lists pair up, to see where the answers come from
+    Distances {
+        #[arg(long)]
+        input: PathBuf,
+        #[arg(long, value_enum, default_value_t = Table::Pairs)]
+        table: Table,
+        #[arg(long, value_enum, default_value_t = Sheet::Csv)]
+        format: Sheet,
+        /// How many pairs the gaps table keeps
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+    },
+    /// List the day 2 reports with whether each is safe, and if not why not
+    Verdicts {
+        #[arg(long)]
+        input: PathBuf,
+        /// How many levels the problem dampener may take out
+        #[arg(long, default_value_t = 0)]
+        tolerance: usize,
+        /// The smallest safe step between levels
+        #[arg(long, default_value_t = 1)]
+        min_step: u8,
+        /// The biggest safe step between levels
+        #[arg(long, default_value_t = 3)]
+        max_step: u8,
+        /// Leave out the safe reports
+        #[arg(long)]
+        unsafe_only: bool,
+    },
+    /// Bytes of RAM each firmware day's buffers take, with the input left in flash
+    Ram {
+        /// Only this day, otherwise every firmware day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+}
+
+/// The first of `day`'s examples in `examples/answers.toml`, built in so an
+/// installed `aoc` doesn't need the repo around, or nothing for a day
+/// without one.
+fn first_example(day: u8) -> &'static str {
+    include!(concat!(env!("OUT_DIR"), "/first_example.rs"))
+}
+
+/// Every input to benchmark `day` on, with a label saying where it came from.
+fn bench_inputs(
+    day: u8,
+    inputs: Option<&Path>,
+    seed: u64,
+    size: Option<usize>,
+) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+
+    if let Some(dir) = inputs {
+        let path = dir.join(format!("day{day:02}.txt"));
+        if let Ok(input) = std::fs::read_to_string(&path) {
+            found.push((path.display().to_string(), input));
+        }
+    }
+
+    let params = Params {
+        size,
+        ..Params::default()
+    };
+    if let Some(input) = aoc_gen::input(day, &params, &mut Rng::new(seed)) {
+        let label = match size {
+            Some(size) => format!("generated seed {seed} size {size}"),
+            None => format!("generated seed {seed}"),
+        };
+        found.push((label, input));
+    }
+
+    found
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let level = match cli.verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    log::set_logger(&Stderr).unwrap();
+    log::set_max_level(level);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input: input_path,
+            format,
+            setup,
+        } => {
+            let puzzle = match days::puzzle_with(day, &setup.setup()) {
+                Some(Ok(puzzle)) => puzzle,
+                Some(Err(err)) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+                None => {
+                    eprintln!("no solution for day {day}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let input = match std::fs::read_to_string(&input_path) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("could not read {}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let solved = match part.and_then(Part::new) {
+                Some(part) => puzzle.solve(&input, part).map(|answer| {
+                    let mut solved = Solved {
+                        day,
+                        part1: None,
+                        part2: None,
+                    };
+                    match part {
+                        Part::One => solved.part1 = Some(answer),
+                        Part::Two => solved.part2 = Some(answer),
+                    }
+                    solved
+                }),
+                None => puzzle.solve_both(&input).map(|answers| Solved {
+                    day,
+                    part1: Some(answers.part1),
+                    part2: Some(answers.part2),
+                }),
+            };
+            let solved = match solved {
+                Ok(solved) => solved,
+                Err(err) => {
+                    eprintln!("{}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            match format {
+                Output::Text => {
+                    for (part, answer) in [(1, &solved.part1), (2, &solved.part2)] {
+                        if let Some(answer) = answer {
+                            println!("day {day} part {part}: {answer}");
+                        }
+                    }
+                }
+                Output::Json => println!("{}", serde_json::to_string(&solved).unwrap()),
+            }
+        }
+        Command::Input {
+            day,
+            cache,
+            offline,
+            import,
+            rust,
+            c,
+            site,
+        } => {
+            // only a download needs the session, and without one inputs are
+            // imported into and read from the local part of the cache
+            let session = std::env::var("AOC_SESSION").ok();
+            let root = cache.unwrap_or_else(inputs::default_cache);
+            let cache = match &session {
+                Some(session) => Cache::new(&root, session),
+                None => Cache::local(&root),
+            };
+
+            let input = match &import {
+                Some(path) => std::fs::read_to_string(path)
+                    .map_err(inputs::Error::from)
+                    .and_then(|input| cache.put(day, &input)),
+                None => {
+                    let client = session
+                        .as_ref()
+                        .filter(|_| !offline)
+                        .map(|session| Client::new(&site, session));
+                    cache.fetch(day, client.as_ref())
+                }
+            };
+            let input = match input {
+                Ok(input) => input,
+                Err(err @ inputs::Error::NotCached(_)) if session.is_none() && !offline => {
+                    eprintln!(
+                        "{err}, and AOC_SESSION needs to be the site's session cookie to download it"
+                    );
+                    return ExitCode::FAILURE;
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let example = first_example(day);
+            let mut outputs = Vec::new();
+            if let Some(dir) = &rust {
+                outputs.push((dir.join("input.txt"), input.clone()));
+            }
+            if let Some(dir) = &c {
+                let header = Header::new(Style::String)
+                    .array("input", &input)
+                    .array("example1", example);
+                outputs.push((dir.join("inputs.h"), header.to_string()));
+            }
+
+            if outputs.is_empty() {
+                print!("{input}");
+            }
+            for (path, text) in outputs {
+                if let Err(err) = std::fs::write(&path, text) {
+                    eprintln!("could not write {}: {err}", path.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Verify { inputs, day, store } => {
+            let store = match Store::load(&store) {
+                Ok(store) => store,
+                Err(err) => {
+                    eprintln!("could not read {}: {err}", store.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let mut failed = false;
+            for day in day.map_or(1..=25, |day| day..=day) {
+                let Some(puzzle) = days::puzzle(day) else {
+                    continue;
+                };
+                let path = inputs.join(format!("day{day:02}.txt"));
+                let Ok(input) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+
+                let answers = match puzzle.solve_both(&input) {
+                    Ok(answers) => answers,
+                    Err(err) => {
+                        println!("day {day}: FAIL, {}: {err}", path.display());
+                        failed = true;
+                        continue;
+                    }
+                };
+
+                let hash = store::input_hash(&input);
+                for (part, answer) in [(1, answers.part1), (2, answers.part2)] {
+                    let answer = answer.to_string();
+                    // day 25 only has the one puzzle
+                    if answer.is_empty() {
+                        continue;
+                    }
+
+                    match store.correct(day, part, &hash) {
+                        Some(correct) if correct == answer => {
+                            println!("day {day} part {part}: pass");
+                        }
+                        Some(correct) => {
+                            println!(
+                                "day {day} part {part}: FAIL, got {answer} but it's {correct}"
+                            );
+                            failed = true;
+                        }
+                        None => println!("day {day} part {part}: unknown, got {answer}"),
+                    }
+                }
+            }
+
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            input: input_path,
+            store: store_path,
+            site,
+        } => {
+            let Some(puzzle) = days::puzzle(day) else {
+                eprintln!("no solution for day {day}");
+                return ExitCode::FAILURE;
+            };
+            let Ok(session) = std::env::var("AOC_SESSION") else {
+                eprintln!("AOC_SESSION needs to be the site's session cookie");
+                return ExitCode::FAILURE;
+            };
+
+            let input = match std::fs::read_to_string(&input_path) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("could not read {}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut store = match Store::load(&store_path) {
+                Ok(store) => store,
+                Err(err) => {
+                    eprintln!("could not read {}: {err}", store_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let answer = match puzzle.solve(&input, Part::new(part).unwrap()) {
+                Ok(answer) => answer.to_string(),
+                Err(err) => {
+                    eprintln!("{}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let client = Client::new(&site, &session);
+            let hash = store::input_hash(&input);
+            let outcome = site::submit_checked(&client, &mut store, day, part, &hash, &answer);
+            match outcome {
+                Ok(Outcome::Known(known)) => {
+                    println!("day {day} part {part}: not sending {answer}, {known}");
+                }
+                Ok(Outcome::Sent(response)) => {
+                    println!("day {day} part {part}: {answer} is {response}");
+                    if let Err(err) = store.save() {
+                        eprintln!("could not write {}: {err}", store_path.display());
+                        return ExitCode::FAILURE;
+                    }
+                    if response != Response::Correct {
+                        return ExitCode::FAILURE;
+                    }
+                }
+                Err(err) => {
+                    eprintln!("could not send the answer: {err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::ParseLog { log } => {
+            let text = match &log {
+                Some(path) => std::fs::read_to_string(path),
+                None => std::io::read_to_string(std::io::stdin()),
+            };
+            let text = match text {
+                Ok(text) => text,
+                Err(err) => {
+                    eprintln!("could not read the log: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let mut found = false;
+            for report in text.lines().filter_map(Report::parse) {
+                found = true;
+                println!("day {} part 1: {}", report.day, report.part1);
+                println!("day {} part 2: {}", report.day, report.part2);
+            }
+
+            if !found {
+                eprintln!("no answers in the log");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Gen {
+            day,
+            seed,
+            size,
+            swaps,
+        } => {
+            let params = Params { size, swaps };
+            let Some(input) = aoc_gen::input(day, &params, &mut Rng::new(seed)) else {
+                eprintln!("no generator for day {day}");
+                return ExitCode::FAILURE;
+            };
+            print!("{input}");
+        }
+        Command::Vis {
+            day,
+            input: input_path,
+            fps,
+            export,
+            format,
+            scale,
+            setup,
+        } => {
+            let input = match std::fs::read_to_string(&input_path) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("could not read {}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let frames = match days::frames(day, &input, &setup.setup()) {
+                Some(Ok(frames)) => frames,
+                Some(Err(days::Error::Input(err))) => {
+                    eprintln!("{}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+                Some(Err(err)) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+                None => {
+                    eprintln!("no visualisation for day {day}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let shown = match &export {
+                Some(dir) => aoc_vis::export(frames, dir, format, scale)
+                    .map(|written| println!("wrote {written} frames to {}", dir.display())),
+                None => aoc_vis::play(frames, fps),
+            };
+            if let Err(err) = shown {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            day,
+            inputs,
+            seed,
+            size,
+            runs,
+            save,
+            baseline,
+            tolerance,
+        } => {
+            let baseline: Option<Vec<Measurement>> = match &baseline {
+                Some(path) => match std::fs::read_to_string(path)
+                    .map_err(|err| err.to_string())
+                    .and_then(|json| serde_json::from_str(&json).map_err(|err| err.to_string()))
+                {
+                    Ok(baseline) => Some(baseline),
+                    Err(err) => {
+                        eprintln!("could not read {}: {err}", path.display());
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => None,
+            };
+
+            let mut measurements = Vec::new();
+            let mut failed = false;
+            for day in day.map_or(1..=25, |day| day..=day) {
+                let Some(puzzle) = days::puzzle(day) else {
+                    continue;
+                };
+
+                for (label, input) in bench_inputs(day, inputs.as_deref(), seed, size) {
+                    let host = bench::caught(|| {
+                        bench::median(runs, |clock| {
+                            let (_, timings) = puzzle.solve_timed(&input, clock)?;
+                            Ok(timings)
+                        })
+                        .map(|timings| Measurement::new(day, "host", &label, timings))
+                        .map_err(|err: InputError| err.to_string())
+                    });
+
+                    let no_std = aoc_firmware::DAYS.contains(&day).then(|| {
+                        bench::caught(|| {
+                            let timed = bench::median(runs, |clock| {
+                                // only the days in DAYS get here
+                                let solved =
+                                    aoc_firmware::solve_timed(day, input.as_bytes(), clock)
+                                        .unwrap();
+                                let (_, _, timings) = solved?;
+                                Ok(timings)
+                            });
+                            match timed {
+                                Ok(timings) => {
+                                    Ok(Ok(Measurement::new(day, "no_std", &label, timings)))
+                                }
+                                Err(InputError::Capacity(full)) => Ok(Err(full)),
+                                Err(err) => Err(err.to_string()),
+                            }
+                        })
+                    });
+
+                    for measured in [Some(host.map(Ok)), no_std].into_iter().flatten() {
+                        match measured {
+                            Ok(Ok(measurement)) => {
+                                println!("{measurement}");
+                                measurements.push(measurement);
+                            }
+                            // the firmware's buffers are sized for real
+                            // inputs, and generated ones can outgrow them
+                            Ok(Err(full)) => {
+                                eprintln!("day {day} no_std on {label}: skipped, {full}")
+                            }
+                            Err(err) => {
+                                eprintln!("day {day} on {label}: {err}");
+                                failed = true;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(path) = &save {
+                let json = serde_json::to_string_pretty(&measurements).unwrap();
+                if let Err(err) = std::fs::write(path, json) {
+                    eprintln!("could not write {}: {err}", path.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            if let Some(baseline) = baseline {
+                let regressions = bench::regressions(&baseline, &measurements, tolerance);
+                for regression in &regressions {
+                    eprintln!("regression: {regression}");
+                }
+                failed |= !regressions.is_empty();
+            }
+
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Distances {
+            input: input_path,
+            table,
+            format,
+            top,
+        } => {
+            let input = match std::fs::read_to_string(&input_path) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("could not read {}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let (left, right) = match Day01.parse(&input) {
+                Ok(columns) => columns,
+                Err(err) => {
+                    eprintln!("{}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let pairs = report::pairs(&left, &right);
+            let out = match table {
+                Table::Pairs => sheet(&pairs, format),
+                Table::Gaps => sheet(&report::widest(&pairs, top), format),
+                Table::Counts => sheet(&report::counts(&left, &right), format),
+            };
+            print!("{out}");
+        }
+        Command::Verdicts {
+            input: input_path,
+            tolerance,
+            min_step,
+            max_step,
+            unsafe_only,
+        } => {
+            let input = match std::fs::read_to_string(&input_path) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("could not read {}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let rules = Rules {
+                min_step,
+                max_step,
+                tolerance,
+            };
+            let reports = match r02_host::reports(&input, rules) {
+                Ok(reports) => reports,
+                Err(err) => {
+                    eprintln!("{}: {err}", input_path.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            for report in reports {
+                if unsafe_only && report.verdict.is_safe() {
+                    continue;
+                }
+                let levels: Vec<String> = report.levels.iter().map(u8::to_string).collect();
+                println!(
+                    "{:4}: {}: {}",
+                    report.line,
+                    levels.join(" "),
+                    report.verdict
+                );
+            }
+        }
+        Command::Ram { day } => {
+            let days = match day {
+                Some(day) => vec![day],
+                None => aoc_firmware::DAYS.to_vec(),
+            };
+            for day in days {
+                match aoc_firmware::ram(day) {
+                    Some(bytes) => println!("day {day:2} {bytes:>8} bytes"),
+                    None => {
+                        eprintln!("no firmware solution for day {day}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
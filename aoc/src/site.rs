@@ -0,0 +1,210 @@
+//! Talking to adventofcode.com: fetching inputs, sending answers, and
+//! making sense of what comes back.
+//!
+//! The site has no API for answers, just the form on each puzzle page, so
+//! the reply is an HTML page to pick the verdict out of. `Store::known` should
+//! be asked first, so that nothing already known to be wrong is sent again.
+
+use crate::store::{Hint, Known, Record, Store, Verdict};
+use std::fmt;
+use std::time::Duration;
+
+pub const SITE: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+/// What the site made of an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    Wrong(Option<Hint>),
+    /// Answers are rate limited, and this one came too soon after the last.
+    TooSoon,
+    /// The part is already solved, or part 1 isn't yet.
+    WrongLevel,
+    /// Anything else, as the page's text.
+    Unrecognised(String),
+}
+
+impl Response {
+    /// Reads the verdict out of the page the site sends back.
+    pub fn parse(page: &str) -> Self {
+        // the verdict is the only <article> on the page
+        let text = page
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+
+        if text.contains("That's the right answer") {
+            Response::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Response::Wrong(hint)
+        } else if text.contains("You gave an answer too recently") {
+            Response::TooSoon
+        } else if text.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else {
+            Response::Unrecognised(strip_tags(text).trim().to_owned())
+        }
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Correct => write!(f, "right"),
+            Response::Wrong(Some(hint)) => write!(f, "wrong, {hint}"),
+            Response::Wrong(None) => write!(f, "wrong"),
+            Response::TooSoon => write!(f, "too soon after the last answer, try again later"),
+            Response::WrongLevel => {
+                write!(f, "not the part the site expected, is it solved already?")
+            }
+            Response::Unrecognised(text) => write!(f, "no verdict in the reply: {text}"),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for chr in html.chars() {
+        match chr {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(chr),
+            _ => {}
+        }
+    }
+    text
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    /// `SITE`, or a stub server under test.
+    base: String,
+    session: String,
+}
+
+impl Client {
+    /// `session` is the value of the site's `session` cookie after logging in.
+    pub fn new(base: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION")))
+                .build(),
+            base: base.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+        }
+    }
+
+    /// The input for `day`, exactly as the site serves it.
+    pub fn input(&self, day: u8) -> Result<String, Box<ureq::Error>> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base);
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()
+            .map_err(|err| Box::new(err.into()))
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Response, Box<ureq::Error>> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base);
+        let page = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()
+            .map_err(|err| Box::new(err.into()))?;
+        Ok(Response::parse(&page))
+    }
+}
+
+/// What came of `submit_checked`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Not sent, since the store already knew better.
+    Known(Known),
+    Sent(Response),
+}
+
+/// Sends `answer` for the input hashed as `input`, unless `store` already
+/// rules it out, and records the verdict in `store` if the site gives one.
+pub fn submit_checked(
+    client: &Client,
+    store: &mut Store,
+    day: u8,
+    part: u8,
+    input: &str,
+    answer: &str,
+) -> Result<Outcome, Box<ureq::Error>> {
+    if let Some(known) = store.known(day, part, input, answer) {
+        return Ok(Outcome::Known(known));
+    }
+
+    let response = client.submit(day, part, answer)?;
+    let verdict = match response {
+        Response::Correct => Some((Verdict::Correct, None)),
+        Response::Wrong(hint) => Some((Verdict::Wrong, hint)),
+        _ => None,
+    };
+    if let Some((verdict, hint)) = verdict {
+        store.record(Record {
+            day,
+            part,
+            input: input.to_owned(),
+            value: answer.to_owned(),
+            verdict,
+            hint,
+        });
+    }
+
+    Ok(Outcome::Sent(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let page = |text| format!("<html><main><article><p>{text}</p></article></main></html>");
+
+        assert_eq!(
+            Response::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Response::Correct
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Response::Wrong(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer. <a href=\"/2024/day/1\">[Return]</a>"
+            )),
+            Response::Wrong(None)
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "You gave an answer too recently; you have 42s left to wait."
+            )),
+            Response::TooSoon
+        );
+        assert_eq!(
+            Response::parse(&page("<b>Something</b> else")),
+            Response::Unrecognised("Something else".to_owned())
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! What we know about each answer, kept in `answers.toml` so nobody has to
+//! rerun a day, or ask the site, to find out whether it's right.
+//!
+//! Answers are keyed by day, part and a hash of the input, since every
+//! account gets a different input:
+//!
+//! ```toml
+//! [[answer]]
+//! day = 1
+//! part = 1
+//! input = "5d8e3c4f9a0b2716"
+//! value = "1830467"
+//! verdict = "correct"
+//!
+//! [[answer]]
+//! day = 1
+//! part = 2
+//! input = "5d8e3c4f9a0b2716"
+//! value = "26674158"
+//! verdict = "wrong"
+//! hint = "too high"
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+}
+
+/// What the site sometimes says about a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Hint {
+    #[serde(rename = "too high")]
+    TooHigh,
+    #[serde(rename = "too low")]
+    TooLow,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// `input_hash` of the input the answer was for.
+    pub input: String,
+    pub value: String,
+    pub verdict: Verdict,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// Why an answer isn't worth sending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Known {
+    /// This part is already solved, with this answer.
+    Solved(String),
+    /// This exact answer was turned down before.
+    Wrong,
+    /// Another answer was turned down as too high or low, and this one is
+    /// no better.
+    Beyond(Hint, String),
+}
+
+impl fmt::Display for Known {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Known::Solved(answer) => write!(f, "already solved, the answer is {answer}"),
+            Known::Wrong => write!(f, "already known to be wrong"),
+            Known::Beyond(hint, wrong) => write!(f, "{wrong} was already {hint}"),
+        }
+    }
+}
+
+/// A hash of `input` that stays the same between builds and machines. It
+/// only has to tell one account's input from another's, so FNV-1a will do.
+pub fn input_hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+#[derive(Serialize, Deserialize)]
+struct File {
+    #[serde(default)]
+    answer: Vec<Record>,
+}
+
+pub struct Store {
+    path: PathBuf,
+    pub records: Vec<Record>,
+}
+
+impl Store {
+    /// Reads the store at `path`, which starts out empty if there isn't one yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let records = match std::fs::read_to_string(path) {
+            Ok(text) => {
+                let file: File = toml::from_str(&text)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+                file.answer
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self {
+            path: path.to_owned(),
+            records,
+        })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let file = File {
+            answer: self.records.clone(),
+        };
+        let text = toml::to_string(&file).map_err(io::Error::other)?;
+        std::fs::write(&self.path, text)
+    }
+
+    fn answers(&self, day: u8, part: u8, input: &str) -> impl Iterator<Item = &Record> {
+        self.records
+            .iter()
+            .filter(move |record| (record.day, record.part, &*record.input) == (day, part, input))
+    }
+
+    /// The right answer for this part of this input, if it's known.
+    pub fn correct(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers(day, part, input)
+            .find(|record| record.verdict == Verdict::Correct)
+            .map(|record| record.value.as_str())
+    }
+
+    /// Whether what's known already rules out `value`.
+    pub fn known(&self, day: u8, part: u8, input: &str, value: &str) -> Option<Known> {
+        if let Some(correct) = self.correct(day, part, input) {
+            return Some(Known::Solved(correct.to_owned()));
+        }
+
+        let number = value.parse::<i128>().ok();
+        for record in self.answers(day, part, input) {
+            if record.value == value {
+                return Some(Known::Wrong);
+            }
+
+            let (Some(number), Ok(wrong), Some(hint)) =
+                (number, record.value.parse::<i128>(), record.hint)
+            else {
+                continue;
+            };
+            let beyond = match hint {
+                Hint::TooHigh => number >= wrong,
+                Hint::TooLow => number <= wrong,
+            };
+            if beyond {
+                return Some(Known::Beyond(hint, record.value.clone()));
+            }
+        }
+
+        None
+    }
+
+    pub fn record(&mut self, record: Record) {
+        self.records.push(record);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrong(value: &str, hint: Option<Hint>) -> Record {
+        Record {
+            day: 1,
+            part: 2,
+            input: input_hash("3   4\n"),
+            value: value.to_owned(),
+            verdict: Verdict::Wrong,
+            hint,
+        }
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash("3   4\n"), input_hash("3   4"));
+        assert_ne!(input_hash("3   4"), input_hash("4   3"));
+        assert_eq!(input_hash("").len(), 16);
+    }
+
+    #[test]
+    fn test_known() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = Store::load(&dir.path().join("answers.toml")).unwrap();
+        let input = input_hash("3   4");
+
+        store.record(wrong("40", Some(Hint::TooHigh)));
+        store.record(wrong("10", Some(Hint::TooLow)));
+        store.record(wrong("twenty", None));
+        assert_eq!(store.known(1, 2, &input, "20"), None);
+        assert_eq!(store.known(1, 2, &input, "twenty"), Some(Known::Wrong));
+        assert_eq!(
+            store.known(1, 2, &input, "41"),
+            Some(Known::Beyond(Hint::TooHigh, "40".to_owned()))
+        );
+        assert_eq!(store.known(1, 2, &input, "10"), Some(Known::Wrong));
+        assert_eq!(store.known(1, 1, &input, "41"), None);
+
+        store.record(Record {
+            value: "31".to_owned(),
+            verdict: Verdict::Correct,
+            hint: None,
+            ..wrong("", None)
+        });
+        assert_eq!(store.correct(1, 2, &input), Some("31"));
+        assert_eq!(
+            store.known(1, 2, &input, "20"),
+            Some(Known::Solved("31".to_owned()))
+        );
+
+        store.save().unwrap();
+        let loaded = Store::load(&dir.path().join("answers.toml")).unwrap();
+        assert_eq!(loaded.records, store.records);
+    }
+}
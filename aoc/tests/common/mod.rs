@@ -0,0 +1,64 @@
+//! A stand-in for adventofcode.com on localhost.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+
+/// What the stub was sent: the request line, cookie and any form.
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub line: String,
+    pub cookie: String,
+    pub form: String,
+}
+
+/// Serves each of `replies` in turn, as a status code and body, and hands
+/// back the requests it got along with the stub's URL.
+pub fn stub(replies: Vec<(u16, String)>) -> (String, mpsc::Receiver<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (sent, received) = mpsc::channel();
+
+    thread::spawn(move || {
+        for (status, body) in replies {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let (mut cookie, mut length) = (String::new(), 0);
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let Some((name, value)) = header.trim_end().split_once(": ") else {
+                    break;
+                };
+                match name.to_ascii_lowercase().as_str() {
+                    "cookie" => cookie = value.to_owned(),
+                    "content-length" => length = value.parse().unwrap(),
+                    _ => {}
+                }
+            }
+            let mut form = vec![0; length];
+            reader.read_exact(&mut form).unwrap();
+
+            sent.send(Request {
+                line: line.trim_end().to_owned(),
+                cookie,
+                form: String::from_utf8(form).unwrap(),
+            })
+            .unwrap();
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (url, received)
+}
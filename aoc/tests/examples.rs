@@ -0,0 +1,24 @@
+//! Every day against the puzzle examples, see `examples/answers.toml`.
+
+use aoc::days;
+use aoc_core::{Part, Setup};
+use std::path::Path;
+
+fn read(path: &str) -> String {
+    std::fs::read_to_string(Path::new(path)).unwrap()
+}
+
+fn check_host(day: u8, path: &str, part: u8, answer: &str, setup: &Setup) {
+    let puzzle = days::puzzle_with(day, setup).unwrap().unwrap();
+    let part = Part::new(part).unwrap();
+    assert_eq!(puzzle.solve(&read(path), part).unwrap().to_string(), answer);
+}
+
+/// None of the firmware days need setting up.
+fn check_no_std(day: u8, path: &str, part: u8, answer: &str, _: &Setup) {
+    let answers = aoc_firmware::solve(day, read(path).as_bytes()).unwrap();
+    let solved = if part == 1 { answers.0 } else { answers.1 };
+    assert_eq!(solved, answer);
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,102 @@
+//! Every generator makes inputs the solvers accept.
+
+use aoc::days;
+use aoc_core::answer::Answers;
+use aoc_core::rng::Rng;
+use aoc_core::{Part, Setup};
+use aoc_gen::Params;
+use std::collections::{BTreeSet, HashMap};
+
+/// Solves both parts of a generated input, at the size it was made for.
+fn solve(day: u8, params: &Params, seed: u64) -> (String, Answers) {
+    let input = aoc_gen::input(day, params, &mut Rng::new(seed)).unwrap();
+    // day 18's memory space is as big as the input, with about as much of
+    // it fallen as in the real one
+    let setup = match (day, params.size) {
+        (18, Some(size)) => Setup {
+            width: Some(size),
+            height: Some(size),
+            drops: Some(size * size / 5),
+            ..Setup::default()
+        },
+        _ => Setup::default(),
+    };
+    let puzzle = days::puzzle_with(day, &setup).unwrap().unwrap();
+    match puzzle.solve_both(&input) {
+        Ok(answers) => (input, answers),
+        Err(err) => panic!("day {day} seed {seed}: {err}\n{input}"),
+    }
+}
+
+#[test]
+fn test_generated_inputs_solve() {
+    for day in aoc_gen::DAYS {
+        // even optimised, day 22's part 2 is slow on more than one buyer
+        let size = if day == 22 { 1 } else { 12 };
+        let params = Params {
+            size: Some(size),
+            ..Params::default()
+        };
+        for seed in 0..5 {
+            solve(day, &params, seed);
+        }
+    }
+}
+
+#[test]
+fn test_generated_quines() {
+    let day17 = days::puzzle(17).unwrap();
+    for seed in 0..5 {
+        let (input, answers) = solve(17, &Params::default(), seed);
+        // the program again, starting from the part 2 answer
+        let (_, rest) = input.split_once('\n').unwrap();
+        let quine = format!("Register A: {}\n{rest}", answers.part2);
+        let printed = day17.solve(&quine, Part::new(1).unwrap()).unwrap();
+        let program = input.lines().last().unwrap();
+        assert_eq!(format!("Program: {printed}"), program, "seed {seed}");
+    }
+}
+
+/// The gates of a day 24 input by what they read, with what they write.
+fn gates(input: &str) -> HashMap<(BTreeSet<&str>, &str), &str> {
+    let (_, gates) = input.split_once("\n\n").unwrap();
+    gates
+        .lines()
+        .map(|gate| {
+            let [a, op, b, _, out] = gate.split(' ').collect::<Vec<_>>()[..] else {
+                panic!("not a gate: {gate}");
+            };
+            ((BTreeSet::from([a, b]), op), out)
+        })
+        .collect()
+}
+
+#[test]
+fn test_generated_swaps() {
+    for swaps in 0..=6 {
+        let params = Params {
+            swaps,
+            ..Params::default()
+        };
+        let unswapped = Params { swaps: 0, ..params };
+        for seed in 0..5 {
+            let (input, answers) = solve(24, &params, seed);
+            // the wires are named before any are swapped, so the same seed
+            // without swaps has the same gates writing the right wires
+            let (correct, _) = solve(24, &unswapped, seed);
+            let correct = gates(&correct);
+            let mut swapped: Vec<_> = gates(&input)
+                .into_iter()
+                .filter(|(gate, out)| correct[gate] != *out)
+                .map(|(_, out)| out)
+                .collect();
+            swapped.sort_unstable();
+            assert_eq!(swapped.len(), 2 * swaps, "{swaps} swaps, seed {seed}");
+            assert_eq!(
+                answers.part2.to_string(),
+                swapped.join(","),
+                "{swaps} swaps, seed {seed}"
+            );
+        }
+    }
+}
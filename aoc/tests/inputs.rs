@@ -0,0 +1,128 @@
+//! Fetching inputs from a stand-in for the site, and working from the cache.
+
+use aoc::inputs::{Cache, Error, Malformed};
+use aoc::site::Client;
+use std::process::Command;
+
+mod common;
+
+const INPUT: &str = "3   4\n4   3\n2   5\n";
+
+#[test]
+fn test_fetched_once() {
+    let (url, requests) = common::stub(vec![(200, INPUT.to_owned())]);
+    let client = Client::new(&url, "53616c7465645f5f");
+    let dir = tempfile::tempdir().unwrap();
+    let cache = Cache::new(dir.path(), "53616c7465645f5f");
+
+    assert!(matches!(cache.fetch(1, None), Err(Error::NotCached(1))));
+    assert_eq!(cache.fetch(1, Some(&client)).unwrap(), INPUT);
+    let request = requests.recv().unwrap();
+    assert_eq!(request.line, "GET /2024/day/1/input HTTP/1.1");
+    assert_eq!(request.cookie, "session=53616c7465645f5f");
+
+    // from the cache now, even with the site to hand
+    assert_eq!(cache.fetch(1, Some(&client)).unwrap(), INPUT);
+    assert_eq!(cache.fetch(1, None).unwrap(), INPUT);
+    assert!(requests.try_recv().is_err());
+
+    // another account's inputs are kept apart
+    let other = Cache::new(dir.path(), "another session");
+    assert!(matches!(other.fetch(1, None), Err(Error::NotCached(1))));
+}
+
+#[test]
+fn test_not_what_the_site_sends() {
+    let (url, _requests) = common::stub(vec![
+        (200, "3   4\n\n".to_owned()),
+        (400, "Please log in to get your puzzle input.\n".to_owned()),
+    ]);
+    let client = Client::new(&url, "expired");
+    let dir = tempfile::tempdir().unwrap();
+    let cache = Cache::new(dir.path(), "expired");
+
+    let err = cache.fetch(2, Some(&client)).unwrap_err();
+    assert!(matches!(err, Error::Malformed(_, Malformed::Ending(2))));
+    assert!(matches!(cache.fetch(3, Some(&client)), Err(Error::Http(_))));
+    assert!(matches!(cache.fetch(2, None), Err(Error::NotCached(2))));
+
+    // hand-copied inputs are tidied up on the way in
+    assert_eq!(cache.put(2, "3   4\r\n\r\n").unwrap(), "3   4\n");
+    assert_eq!(cache.fetch(2, None).unwrap(), "3   4\n");
+
+    std::fs::write(cache.path(2), "3   4").unwrap();
+    let err = cache.fetch(2, None).unwrap_err();
+    assert!(matches!(err, Error::Malformed(_, Malformed::Ending(0))));
+}
+
+#[test]
+fn test_written_out_offline() {
+    let dir = tempfile::tempdir().unwrap();
+    let copied = dir.path().join("copied.txt");
+    std::fs::write(&copied, INPUT.trim_end()).unwrap();
+
+    let aoc = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["input", "--day", "1", "--cache"])
+            .arg(dir.path().join("cache"))
+            .args(args)
+            .env("AOC_SESSION", "53616c7465645f5f")
+            .output()
+            .unwrap()
+    };
+
+    let output = aoc(&["--offline"]);
+    assert!(!output.status.success());
+
+    assert!(
+        aoc(&["--import", copied.to_str().unwrap()])
+            .status
+            .success()
+    );
+    let output = aoc(&["--offline"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), INPUT);
+
+    let out = dir.path().to_str().unwrap();
+    assert!(
+        aoc(&["--offline", "--rust", out, "--c", out])
+            .status
+            .success()
+    );
+    let written = std::fs::read_to_string(dir.path().join("input.txt")).unwrap();
+    assert_eq!(written, INPUT);
+    let header = std::fs::read_to_string(dir.path().join("inputs.h")).unwrap();
+    assert!(header.contains("static char input[] =\n    \"3   4\\n\"\n"));
+}
+
+#[test]
+fn test_imported_without_a_session() {
+    let dir = tempfile::tempdir().unwrap();
+    let copied = dir.path().join("copied.txt");
+    std::fs::write(&copied, INPUT).unwrap();
+
+    let aoc = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["input", "--day", "1", "--cache"])
+            .arg(dir.path().join("cache"))
+            .args(args)
+            .env_remove("AOC_SESSION")
+            .output()
+            .unwrap()
+    };
+
+    // with nothing cached there's nothing to do without downloading
+    let output = aoc(&[]);
+    assert!(!output.status.success());
+    let error = String::from_utf8(output.stderr).unwrap();
+    assert!(error.contains("AOC_SESSION"), "{error}");
+
+    assert!(
+        aoc(&["--import", copied.to_str().unwrap()])
+            .status
+            .success()
+    );
+    for args in [&["--offline"][..], &[]] {
+        let output = aoc(args);
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), INPUT);
+    }
+}
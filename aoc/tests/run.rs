@@ -0,0 +1,79 @@
+//! `aoc run` as a script would call it.
+
+use std::process::Command;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("run")
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_text() {
+    let output = run(&["--day", "1", "--input", "../examples/day01/example.txt"]);
+    assert_eq!(output, "day 1 part 1: 11\nday 1 part 2: 31\n");
+}
+
+#[test]
+fn test_json() {
+    let output = run(&[
+        "--day",
+        "23",
+        "--input",
+        "../examples/day23/example.txt",
+        "--format",
+        "json",
+    ]);
+    assert_eq!(
+        output,
+        "{\"day\":23,\"part1\":7,\"part2\":\"co,de,ka,ta\"}\n"
+    );
+
+    let output = run(&[
+        "--day",
+        "1",
+        "--part",
+        "2",
+        "--input",
+        "../examples/day01/example.txt",
+        "--format",
+        "json",
+    ]);
+    assert_eq!(output, "{\"day\":1,\"part2\":31}\n");
+}
+
+#[test]
+fn test_distances() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["distances", "--input", "../examples/day01/example.txt"])
+        .args(["--table", "gaps", "--top", "2"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "index,left,right,distance\n5,4,9,5\n0,1,3,2\n"
+    );
+}
+
+#[test]
+fn test_verdicts() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verdicts", "--input", "../examples/day02/example.txt"])
+        .args(["--tolerance", "1", "--unsafe-only"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "   2: 1 2 7 8 9: unsafe, level 3 changes too much\n   \
+         3: 9 7 6 2 1: unsafe, level 4 changes too much\n"
+    );
+}
@@ -0,0 +1,161 @@
+use aoc_core::protocol::{Decoder, Header, Kind};
+use aoc_core::{Part, Puzzle};
+use nix::fcntl::OFlag;
+use nix::pty::{PtyMaster, grantpt, posix_openpt, ptsname_r, unlockpt};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::os::unix::fs::OpenOptionsExt;
+use std::process::{Command, Output};
+use std::thread;
+
+const EXAMPLE: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+/// Opens a pseudo-terminal, keeping the slave end open so reads on the
+/// master don't fail before `aoc-send` gets to it.
+fn pty() -> (PtyMaster, File, String) {
+    let master = posix_openpt(OFlag::O_RDWR | OFlag::O_NOCTTY).unwrap();
+    grantpt(&master).unwrap();
+    unlockpt(&master).unwrap();
+    let path = ptsname_r(&master).unwrap();
+    let slave = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .custom_flags(nix::libc::O_NOCTTY)
+        .open(&path)
+        .unwrap();
+    (master, slave, path)
+}
+
+/// Plays the Pico: waits for one input frame and sends back whatever
+/// `reply` makes of it.
+fn fake_pico(
+    mut master: PtyMaster,
+    reply: impl FnOnce(Header, &[u8]) -> Vec<u8> + Send + 'static,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut buf = vec![0; 1024];
+        let mut decoder = Decoder::new(&mut buf);
+        let mut chunk = [0; 64];
+
+        loop {
+            let count = master.read(&mut chunk).unwrap();
+            for &byte in &chunk[..count] {
+                if let Some(header) = decoder.push(byte).unwrap() {
+                    master.write_all(&reply(header, decoder.payload())).unwrap();
+                    return;
+                }
+            }
+        }
+    })
+}
+
+fn frame(kind: Kind, day: u8, part: Option<Part>, payload: &str) -> Vec<u8> {
+    let mut bytes = Header::new(kind, day, part, payload.as_bytes())
+        .encode()
+        .to_vec();
+    bytes.extend_from_slice(payload.as_bytes());
+    bytes
+}
+
+fn aoc_send(device: &str, day: &str, input: &str, extra: &[&str]) -> Output {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.txt");
+    std::fs::write(&path, input).unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_aoc-send"))
+        .args(["--device", device, "--day", day, "--input"])
+        .arg(&path)
+        .args(extra)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_both_parts() {
+    let (master, _slave, path) = pty();
+    let pico = fake_pico(master, |header, payload| {
+        assert_eq!(header.kind, Kind::Input);
+        assert_eq!(header.day, 1);
+        assert_eq!(header.part, None);
+
+        let input = std::str::from_utf8(payload).unwrap();
+        let answers = r01_host::Day01.solve_both(input).unwrap();
+        let mut reply = frame(Kind::Answer, 1, Some(Part::One), &answers.part1.to_string());
+        reply.extend(frame(
+            Kind::Answer,
+            1,
+            Some(Part::Two),
+            &answers.part2.to_string(),
+        ));
+        reply
+    });
+
+    let output = aoc_send(&path, "1", EXAMPLE, &[]);
+    pico.join().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "day 1 part 1: 11\nday 1 part 2: 31\n"
+    );
+}
+
+#[test]
+fn test_one_part() {
+    let (master, _slave, path) = pty();
+    let pico = fake_pico(master, |header, _payload| {
+        assert_eq!(header.part, Some(Part::Two));
+        frame(Kind::Answer, 1, Some(Part::Two), "31")
+    });
+
+    let output = aoc_send(&path, "1", EXAMPLE, &["--part", "2"]);
+    pico.join().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "day 1 part 2: 31\n"
+    );
+}
+
+#[test]
+fn test_error_reply() {
+    let (master, _slave, path) = pty();
+    let pico = fake_pico(master, |header, _payload| {
+        frame(Kind::Error, header.day, None, "no solution for day 6")
+    });
+
+    let output = aoc_send(&path, "6", "....#.....\n", &[]);
+    pico.join().unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "day 6: no solution for day 6\n"
+    );
+}
+
+#[test]
+fn test_skips_stray_bytes() {
+    let (master, _slave, path) = pty();
+    let pico = fake_pico(master, |_header, _payload| {
+        let mut reply = b"\r\nboot\r\n".to_vec();
+        reply.extend(frame(Kind::Answer, 1, Some(Part::Two), "31"));
+        reply
+    });
+
+    let output = aoc_send(&path, "1", EXAMPLE, &["--part", "2"]);
+    pico.join().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "day 1 part 2: 31\n"
+    );
+}
@@ -0,0 +1,58 @@
+//! Submitting against a stub of the site on localhost.
+
+use aoc::site::{Client, Outcome, Response, submit_checked};
+use aoc::store::{Hint, Known, Store};
+
+mod common;
+
+/// A page with `verdict` where the site puts it.
+fn page(verdict: &str) -> (u16, String) {
+    let page = format!("<html><main><article><p>{verdict}</p></article></main></html>");
+    (200, page)
+}
+
+#[test]
+fn test_never_sent_twice() {
+    let (url, requests) = common::stub(vec![
+        page("That's not the right answer; your answer is too high."),
+        page("That's the right answer!"),
+    ]);
+    let client = Client::new(&url, "53616c7465645f5f\n");
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("answers.toml");
+    let mut store = Store::load(&path).unwrap();
+
+    let submit = |store: &mut Store, answer| {
+        submit_checked(&client, store, 1, 2, "0123456789abcdef", answer).unwrap()
+    };
+
+    assert_eq!(
+        submit(&mut store, "40"),
+        Outcome::Sent(Response::Wrong(Some(Hint::TooHigh)))
+    );
+    assert_eq!(
+        requests.recv().unwrap(),
+        common::Request {
+            line: "POST /2024/day/1/answer HTTP/1.1".to_owned(),
+            cookie: "session=53616c7465645f5f".to_owned(),
+            form: "level=2&answer=40".to_owned(),
+        }
+    );
+
+    assert_eq!(submit(&mut store, "40"), Outcome::Known(Known::Wrong));
+    assert_eq!(
+        submit(&mut store, "45"),
+        Outcome::Known(Known::Beyond(Hint::TooHigh, "40".to_owned()))
+    );
+
+    assert_eq!(submit(&mut store, "31"), Outcome::Sent(Response::Correct));
+    assert_eq!(requests.recv().unwrap().form, "level=2&answer=31");
+
+    store.save().unwrap();
+    let mut store = Store::load(&path).unwrap();
+    assert_eq!(
+        submit(&mut store, "32"),
+        Outcome::Known(Known::Solved("31".to_owned()))
+    );
+    assert!(requests.try_recv().is_err());
+}
@@ -0,0 +1,94 @@
+//! What a host solver hands back for each part.
+//!
+//! Most days count or add something up, so most answers are numbers, but a
+//! few are text: day 17's program output, day 23's password and day 24's
+//! swapped wires. Keeping them apart lets `aoc run --format json` write
+//! numbers as numbers.
+
+use alloc::string::String;
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Answer {
+    /// Wide enough for whatever integer type a day does its sums in.
+    Number(i128),
+    Text(String),
+}
+
+macro_rules! from_number {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(number: $int) -> Self {
+                    Answer::Number(number.into())
+                }
+            }
+        )*
+    };
+}
+
+from_number!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        Answer::Number(number as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(number: isize) -> Self {
+        Answer::Number(number as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.into())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Both parts' answers to one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Answers {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+impl Answers {
+    pub fn get(&self, part: crate::Part) -> &Answer {
+        match part {
+            crate::Part::One => &self.part1,
+            crate::Part::Two => &self.part2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(-3isize).to_string(), "-3");
+        assert_eq!(Answer::from("co,de,ka,ta").to_string(), "co,de,ka,ta");
+    }
+}
@@ -0,0 +1,112 @@
+use core::fmt;
+
+use crate::parse::ParseError;
+
+/// A fixed size buffer ran out of room part way through the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CapacityError {
+    /// Which buffer filled up.
+    pub buffer: &'static str,
+    pub capacity: usize,
+    /// 1-based input line being processed when it happened.
+    pub line: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is full ({} entries) at input line {}",
+            self.buffer, self.capacity, self.line
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
+/// Turns the item a full buffer hands back into a `CapacityError`.
+pub trait OrFull {
+    fn or_full(
+        self,
+        buffer: &'static str,
+        capacity: usize,
+        line: usize,
+    ) -> Result<(), CapacityError>;
+}
+
+impl<T> OrFull for Result<(), T> {
+    fn or_full(
+        self,
+        buffer: &'static str,
+        capacity: usize,
+        line: usize,
+    ) -> Result<(), CapacityError> {
+        self.map_err(|_| CapacityError {
+            buffer,
+            capacity,
+            line,
+        })
+    }
+}
+
+/// The input reads fine, but what a part is looking for isn't in it, like a
+/// way through a maze that has none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoAnswer {
+    /// Why not, to follow "no answer: ".
+    pub missing: &'static str,
+}
+
+impl fmt::Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no answer: {}", self.missing)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoAnswer {}
+
+/// Why a day's input could not be solved: either it isn't what the puzzle
+/// describes, there is more of it than the buffers were sized for, or a
+/// part has no answer to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InputError {
+    Parse(ParseError),
+    Capacity(CapacityError),
+    NoAnswer(NoAnswer),
+}
+
+impl From<ParseError> for InputError {
+    fn from(err: ParseError) -> Self {
+        InputError::Parse(err)
+    }
+}
+
+impl From<CapacityError> for InputError {
+    fn from(err: CapacityError) -> Self {
+        InputError::Capacity(err)
+    }
+}
+
+impl From<NoAnswer> for InputError {
+    fn from(err: NoAnswer) -> Self {
+        InputError::NoAnswer(err)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Parse(err) => write!(f, "{err}"),
+            InputError::Capacity(err) => write!(f, "{err}"),
+            InputError::NoAnswer(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InputError {}
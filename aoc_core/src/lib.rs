@@ -0,0 +1,132 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+pub mod answer;
+pub mod error;
+pub mod parse;
+pub mod protocol;
+pub mod report;
+pub mod rng;
+pub mod scan;
+pub mod stack;
+pub mod storage;
+pub mod timing;
+pub mod tokens;
+
+#[cfg(feature = "alloc")]
+use answer::{Answer, Answers};
+use core::fmt;
+#[cfg(feature = "alloc")]
+use error::InputError;
+#[cfg(feature = "alloc")]
+use timing::{Clock, Stopwatch, Timings};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn new(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// What a few days need to know that their input doesn't say, and that the
+/// puzzle examples give smaller values for. `None` means the real puzzle's.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Setup {
+    /// Tiles across day 14's room and day 18's memory space.
+    pub width: Option<usize>,
+    /// Tiles down day 14's room and day 18's memory space.
+    pub height: Option<usize>,
+    /// Bytes fallen into day 18's memory space before part 1 looks.
+    pub drops: Option<usize>,
+    /// The fewest picoseconds a day 20 cheat has to save to count.
+    pub saving: Option<usize>,
+}
+
+/// One day's puzzle: parse the input once, then answer either part from it.
+#[cfg(feature = "alloc")]
+pub trait Solver {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer, InputError>;
+    fn part2(&self, input: &Self::Input) -> Result<Answer, InputError>;
+}
+
+/// Object safe view of a `Solver` so the runner can pick a day at runtime.
+#[cfg(feature = "alloc")]
+pub trait Puzzle {
+    fn solve(&self, input: &str, part: Part) -> Result<Answer, InputError>;
+    fn solve_both(&self, input: &str) -> Result<Answers, InputError>;
+    /// `solve_both`, timing the parse and each part on `clock`.
+    fn solve_timed(&self, input: &str, clock: &dyn Clock)
+    -> Result<(Answers, Timings), InputError>;
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Solver> Puzzle for S {
+    fn solve(&self, input: &str, part: Part) -> Result<Answer, InputError> {
+        let parsed = self.parse(input)?;
+        match part {
+            Part::One => self.part1(&parsed),
+            Part::Two => self.part2(&parsed),
+        }
+    }
+
+    fn solve_both(&self, input: &str) -> Result<Answers, InputError> {
+        let parsed = self.parse(input)?;
+        Ok(Answers {
+            part1: self.part1(&parsed)?,
+            part2: self.part2(&parsed)?,
+        })
+    }
+
+    fn solve_timed(
+        &self,
+        input: &str,
+        clock: &dyn Clock,
+    ) -> Result<(Answers, Timings), InputError> {
+        let mut stopwatch = Stopwatch::start(clock);
+        let parsed = self.parse(input)?;
+        let parse_us = stopwatch.lap();
+        let part1 = self.part1(&parsed)?;
+        let part1_us = stopwatch.lap();
+        let part2 = self.part2(&parsed)?;
+        let part2_us = stopwatch.lap();
+
+        let timings = Timings {
+            parse_us,
+            part1_us,
+            part2_us,
+        };
+        Ok((Answers { part1, part2 }, timings))
+    }
+}
@@ -0,0 +1,447 @@
+//! Reading puzzle inputs without panicking.
+//!
+//! A `Cursor` walks one line of the input. Everything that reads from it
+//! says what it was after, so when an input is truncated or pasted wrong the
+//! `ParseError` can point at the line and column where it went off the rails
+//! and say what was there instead.
+
+use core::fmt;
+use core::str::FromStr;
+
+/// What the parser wanted to see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Expected {
+    /// A description, like "a number".
+    Thing(&'static str),
+    /// Exact text, like `" -> "`.
+    Text(&'static str),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Thing(thing) => write!(f, "{thing}"),
+            Expected::Text(text) => write!(f, "{text:?}"),
+        }
+    }
+}
+
+/// What the parser saw instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Found {
+    Char(char),
+    EndOfLine,
+    EndOfInput,
+}
+
+impl fmt::Display for Found {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Found::Char(chr) => write!(f, "{chr:?}"),
+            Found::EndOfLine => write!(f, "the end of the line"),
+            Found::EndOfInput => write!(f, "the end of the input"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParseError {
+    /// 1-based, like the column.
+    pub line: usize,
+    /// Counted in characters, not bytes.
+    pub column: usize,
+    pub expected: Expected,
+    pub found: Found,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Reads one line of the input from left to right.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: usize,
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Self { line, text, pos: 0 }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    /// What is left of the line.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, expected: Expected) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column(),
+            expected,
+            found: self.peek().map_or(Found::EndOfLine, Found::Char),
+        }
+    }
+
+    /// Takes the next character if `valid` likes it.
+    pub fn char(
+        &mut self,
+        valid: impl Fn(char) -> bool,
+        expected: &'static str,
+    ) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(chr) if valid(chr) => {
+                self.pos += chr.len_utf8();
+                Ok(chr)
+            }
+            _ => Err(self.error(Expected::Thing(expected))),
+        }
+    }
+
+    /// Takes exactly `text`, or points at the first character that differs.
+    pub fn tag(&mut self, text: &'static str) -> Result<(), ParseError> {
+        for want in text.chars() {
+            if self.peek() != Some(want) {
+                return Err(self.error(Expected::Text(text)));
+            }
+            self.pos += want.len_utf8();
+        }
+        Ok(())
+    }
+
+    /// Takes the characters `valid` likes, which may be none.
+    pub fn take_while(&mut self, valid: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|chr| !valid(chr)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Takes a decimal number, with a `-` in front if it is negative.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.clone();
+        let sign = usize::from(self.rest().starts_with('-'));
+        let digits = self.rest()[sign..]
+            .find(|chr: char| !chr.is_ascii_digit())
+            .unwrap_or(self.rest().len() - sign);
+        if digits == 0 {
+            return Err(self.error(Expected::Thing("a number")));
+        }
+
+        let number = &self.rest()[..sign + digits];
+        // the digits are fine, so it must be too big or negative for T
+        let number = number
+            .parse()
+            .map_err(|_| start.error(Expected::Thing("a number that fits")))?;
+        self.pos += sign + digits;
+        Ok(number)
+    }
+
+    /// Takes one or more spaces.
+    pub fn spaces(&mut self) -> Result<(), ParseError> {
+        if self.take_while(|chr| chr == ' ').is_empty() {
+            return Err(self.error(Expected::Thing("a space")));
+        }
+        Ok(())
+    }
+
+    /// Checks nothing is left over.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if !self.is_empty() {
+            return Err(self.error(Expected::Thing("the end of the line")));
+        }
+        Ok(())
+    }
+}
+
+/// The lines of an input as `Cursor`s, numbered from 1.
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    lines: core::str::Lines<'a>,
+    line: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            lines: input.lines(),
+            line: 0,
+        }
+    }
+
+    /// The next line, which has to be there.
+    pub fn expect(&mut self, expected: &'static str) -> Result<Cursor<'a>, ParseError> {
+        self.next().ok_or(self.end_of_input(expected))
+    }
+
+    /// An error for running out of input where `expected` should have been.
+    pub fn end_of_input(&self, expected: &'static str) -> ParseError {
+        ParseError {
+            line: self.line + 1,
+            column: 1,
+            expected: Expected::Thing(expected),
+            found: Found::EndOfInput,
+        }
+    }
+
+    /// The lines up to the next blank one, which is skipped, or the end.
+    pub fn section(&mut self) -> impl Iterator<Item = Cursor<'a>> + '_ {
+        self.by_ref().take_while(|line| !line.is_empty())
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Cursor<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.line += 1;
+        Some(Cursor::new(self.line, text))
+    }
+}
+
+/// An error for getting to the end of `input` without seeing `expected`,
+/// like a maze with no start.
+pub fn missing(input: &str, expected: &'static str) -> ParseError {
+    let mut lines = Lines::new(input);
+    lines.by_ref().for_each(drop);
+    lines.end_of_input(expected)
+}
+
+/// Reads a map of single character cells, calling `each` with the 0-based
+/// row and column of every one. All rows must be as wide as the first, and
+/// every cell one that `valid` likes. Returns the width and height.
+pub fn grid<E: From<ParseError>>(
+    input: &str,
+    valid: impl Fn(char) -> bool,
+    expected: &'static str,
+    each: impl FnMut(usize, usize, char) -> Result<(), E>,
+) -> Result<(usize, usize), E> {
+    let mut lines = Lines::new(input);
+    let size = grid_lines(&mut lines, valid, expected, each)?;
+
+    // the map stopped at a blank line
+    if let Some(line) = lines.next() {
+        return Err(line.error(Expected::Thing(expected)).into());
+    }
+    Ok(size)
+}
+
+/// Like `grid`, for a map with more input after it. Reads up to the first
+/// blank line, which is left for the caller.
+pub fn grid_lines<E: From<ParseError>>(
+    lines: &mut Lines,
+    valid: impl Fn(char) -> bool,
+    expected: &'static str,
+    mut each: impl FnMut(usize, usize, char) -> Result<(), E>,
+) -> Result<(usize, usize), E> {
+    let mut width = None;
+    let mut height = 0;
+
+    while let Some(mut line) = lines.clone().next().filter(|line| !line.is_empty()) {
+        lines.next();
+        let mut col = 0;
+        while width.is_none_or(|width| col < width) && !line.is_empty() {
+            each(height, col, line.char(&valid, expected)?)?;
+            col += 1;
+        }
+
+        match width {
+            None => width = Some(col),
+            Some(width) if col < width => {
+                return Err(line.error(Expected::Thing(expected)).into());
+            }
+            Some(_) => line.end()?,
+        }
+        height += 1;
+    }
+
+    match (width, lines.clone().next()) {
+        (Some(width), _) => Ok((width, height)),
+        (None, Some(blank)) => Err(blank.error(Expected::Thing(expected)).into()),
+        (None, None) => Err(lines.end_of_input(expected).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        let mut line = Cursor::new(1, "p=-12,7 v=x");
+        line.tag("p=").unwrap();
+        assert_eq!(line.number::<i32>(), Ok(-12));
+        line.tag(",").unwrap();
+        assert_eq!(line.number::<u8>(), Ok(7));
+        line.tag(" v=").unwrap();
+        assert_eq!(
+            line.number::<u8>(),
+            Err(ParseError {
+                line: 1,
+                column: 11,
+                expected: Expected::Thing("a number"),
+                found: Found::Char('x'),
+            })
+        );
+    }
+
+    #[test]
+    fn test_number_too_big() {
+        let mut line = Cursor::new(3, "12 300");
+        assert_eq!(line.number::<u8>(), Ok(12));
+        line.spaces().unwrap();
+        let err = line.number::<u8>().unwrap_err();
+        assert_eq!(
+            (err.column, err.expected),
+            (4, Expected::Thing("a number that fits"))
+        );
+        // nothing was taken
+        assert_eq!(line.rest(), "300");
+    }
+
+    #[test]
+    fn test_tag_points_at_difference() {
+        let mut line = Cursor::new(2, "Button A: Y+4");
+        let err = line.tag("Button A: X+").unwrap_err();
+        assert_eq!(err.column, 11);
+        assert_eq!(err.found, Found::Char('Y'));
+    }
+
+    #[test]
+    fn test_end() {
+        let mut line = Cursor::new(1, "12");
+        line.number::<u8>().unwrap();
+        assert_eq!(line.end(), Ok(()));
+        let err = line.tag(",").unwrap_err();
+        assert_eq!((err.column, err.found), (3, Found::EndOfLine));
+        assert_eq!(Cursor::new(1, "1 ").end().unwrap_err().column, 1);
+    }
+
+    #[test]
+    fn test_columns_count_chars() {
+        let mut line = Cursor::new(1, "ééx");
+        line.take_while(|chr| chr == 'é');
+        assert_eq!(line.column(), 3);
+    }
+
+    #[test]
+    fn test_sections() {
+        let mut lines = Lines::new("a\nb\n\nc\n");
+        assert_eq!(lines.section().count(), 2);
+        let line = lines.expect("c").unwrap();
+        assert_eq!((line.line(), line.rest()), (4, "c"));
+        assert_eq!(
+            lines.expect("more").unwrap_err(),
+            ParseError {
+                line: 5,
+                column: 1,
+                expected: Expected::Thing("more"),
+                found: Found::EndOfInput,
+            }
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut cells = 0;
+        let size = grid(
+            "#.#\n.S.\n",
+            |chr| "#.S".contains(chr),
+            "a cell",
+            |_, _, _| {
+                cells += 1;
+                Ok::<_, ParseError>(())
+            },
+        );
+        assert_eq!(size, Ok((3, 2)));
+        assert_eq!(cells, 6);
+    }
+
+    #[test]
+    fn test_grid_errors() {
+        let valid = |chr| "#.".contains(chr);
+        let each = |_, _, _| Ok(());
+        let err = |input| grid::<ParseError>(input, valid, "a cell", each).unwrap_err();
+
+        assert_eq!((err("##\n#\n").line, err("##\n#\n").column), (2, 2));
+        assert_eq!(err("##\n#\n").found, Found::EndOfLine);
+        assert_eq!(
+            (err("##\n###\n").column, err("##\n###\n").found),
+            (3, Found::Char('#'))
+        );
+        assert_eq!(
+            (err("#x\n").column, err("#x\n").found),
+            (2, Found::Char('x'))
+        );
+        assert_eq!(err("").found, Found::EndOfInput);
+    }
+
+    #[test]
+    fn test_grid_lines() {
+        let mut lines = Lines::new("#.\n.#\n\n<>\n");
+        let size = grid_lines::<ParseError>(
+            &mut lines,
+            |chr| "#.".contains(chr),
+            "a cell",
+            |_, _, _| Ok(()),
+        );
+        assert_eq!(size, Ok((2, 2)));
+        // the blank line is left
+        assert!(lines.next().unwrap().is_empty());
+
+        let err = grid::<ParseError>(
+            "#.\n\n.#\n",
+            |chr| "#.".contains(chr),
+            "a cell",
+            |_, _, _| Ok(()),
+        );
+        assert_eq!(err.unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_missing() {
+        let err = missing("#.#\n#.#\n", "the start");
+        assert_eq!((err.line, err.found), (3, Found::EndOfInput));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = Cursor::new(4, "7 -> x").error(Expected::Text(" -> "));
+        assert_eq!(
+            std::format!("{err}"),
+            "line 4, column 1: expected \" -> \", found '7'"
+        );
+    }
+}
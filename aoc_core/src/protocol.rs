@@ -0,0 +1,348 @@
+//! Framing for handing puzzle inputs to the Pico over USB serial.
+//!
+//! Every frame is a 7 byte header followed by `len` bytes of payload:
+//!
+//! | byte   | meaning                                        |
+//! |--------|------------------------------------------------|
+//! | 0      | kind: `I` input, `A` answer, `E` error         |
+//! | 1      | day                                            |
+//! | 2      | part: 1, 2, or 0 for both / not applicable     |
+//! | 3..7   | payload length, little endian `u32`            |
+//!
+//! The host sends one `I` frame carrying the puzzle input. The Pico replies
+//! with an `A` frame per part asked for, or a single `E` frame holding a
+//! message if it could not solve it.
+
+use crate::Part;
+use core::fmt;
+
+pub const HEADER_LEN: usize = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Kind {
+    Input,
+    Answer,
+    Error,
+}
+
+impl Kind {
+    fn tag(self) -> u8 {
+        match self {
+            Kind::Input => b'I',
+            Kind::Answer => b'A',
+            Kind::Error => b'E',
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            b'I' => Some(Kind::Input),
+            b'A' => Some(Kind::Answer),
+            b'E' => Some(Kind::Error),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Header {
+    pub kind: Kind,
+    pub day: u8,
+    /// `None` asks for both parts.
+    pub part: Option<Part>,
+    pub len: u32,
+}
+
+impl Header {
+    pub fn new(kind: Kind, day: u8, part: Option<Part>, payload: &[u8]) -> Self {
+        Self {
+            kind,
+            day,
+            part,
+            len: payload.len() as u32,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; HEADER_LEN] {
+        let part = match self.part {
+            None => 0,
+            Some(Part::One) => 1,
+            Some(Part::Two) => 2,
+        };
+        let len = self.len.to_le_bytes();
+
+        [
+            self.kind.tag(),
+            self.day,
+            part,
+            len[0],
+            len[1],
+            len[2],
+            len[3],
+        ]
+    }
+
+    pub fn decode(bytes: &[u8; HEADER_LEN]) -> Result<Self, FrameError> {
+        let kind = Kind::from_tag(bytes[0]).ok_or(FrameError::UnknownKind(bytes[0]))?;
+        let part = match bytes[2] {
+            0 => None,
+            part => Some(Part::new(part).ok_or(FrameError::BadPart(part))?),
+        };
+
+        Ok(Self {
+            kind,
+            day: bytes[1],
+            part,
+            len: u32::from_le_bytes([bytes[3], bytes[4], bytes[5], bytes[6]]),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FrameError {
+    /// A frame started with a byte that isn't a known kind; it gets dropped.
+    UnknownKind(u8),
+    /// A frame asked for a part that doesn't exist; its payload is skipped.
+    BadPart(u8),
+    /// The payload doesn't fit the receive buffer and is skipped.
+    TooLong { len: u32, capacity: usize },
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameError::UnknownKind(tag) => write!(f, "unknown frame kind {tag:#04x}"),
+            FrameError::BadPart(part) => write!(f, "there is no part {part}"),
+            FrameError::TooLong { len, capacity } => {
+                write!(
+                    f,
+                    "payload of {len} bytes is over the {capacity} byte buffer"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FrameError {}
+
+/// Reassembles frames from a byte stream that arrives in arbitrary chunks.
+pub struct Decoder<'a> {
+    buf: &'a mut [u8],
+    header: [u8; HEADER_LEN],
+    header_len: usize,
+    frame: Option<Header>,
+    filled: usize,
+    skip: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Payloads are collected into `buf`, so it bounds the largest input.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self {
+            buf,
+            header: [0; HEADER_LEN],
+            header_len: 0,
+            frame: None,
+            filled: 0,
+            skip: 0,
+        }
+    }
+
+    /// Feeds in the next byte, handing back the header once its frame is
+    /// complete. The payload stays readable until the next frame starts.
+    pub fn push(&mut self, byte: u8) -> Result<Option<Header>, FrameError> {
+        if self.skip > 0 {
+            self.skip -= 1;
+            return Ok(None);
+        }
+
+        if let Some(header) = self.frame {
+            self.buf[self.filled] = byte;
+            self.filled += 1;
+            if self.filled == header.len as usize {
+                self.frame = None;
+                return Ok(Some(header));
+            }
+            return Ok(None);
+        }
+
+        if self.header_len == 0 && Kind::from_tag(byte).is_none() {
+            return Err(FrameError::UnknownKind(byte));
+        }
+
+        self.header[self.header_len] = byte;
+        self.header_len += 1;
+        if self.header_len < HEADER_LEN {
+            return Ok(None);
+        }
+
+        self.header_len = 0;
+        self.filled = 0;
+        let header = match Header::decode(&self.header) {
+            Ok(header) => header,
+            Err(err) => {
+                // only the part can be wrong by now, and the length is still
+                // good, so the payload is skipped rather than read as frames
+                let len = &self.header[3..];
+                self.skip = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+                return Err(err);
+            }
+        };
+
+        if header.len as usize > self.buf.len() {
+            self.skip = header.len as usize;
+            return Err(FrameError::TooLong {
+                len: header.len,
+                capacity: self.buf.len(),
+            });
+        }
+
+        if header.len == 0 {
+            return Ok(Some(header));
+        }
+
+        self.frame = Some(header);
+        Ok(None)
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        &self.buf[..self.filled]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::vec::Vec;
+
+    fn frame(header: Header, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = header.encode().to_vec();
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn decode_all(
+        decoder: &mut Decoder,
+        bytes: &[u8],
+    ) -> Vec<Result<(Header, Vec<u8>), FrameError>> {
+        let mut frames = Vec::new();
+        for &byte in bytes {
+            match decoder.push(byte) {
+                Ok(Some(header)) => frames.push(Ok((header, decoder.payload().to_vec()))),
+                Ok(None) => {}
+                Err(err) => frames.push(Err(err)),
+            }
+        }
+        frames
+    }
+
+    #[test]
+    fn test_header_round_trip() {
+        for part in [None, Some(Part::One), Some(Part::Two)] {
+            let header = Header::new(Kind::Input, 11, part, b"125 17\n");
+            assert_eq!(Header::decode(&header.encode()), Ok(header));
+        }
+    }
+
+    #[test]
+    fn test_bad_part() {
+        let mut bytes = Header::new(Kind::Input, 1, None, b"").encode();
+        bytes[2] = 3;
+        assert_eq!(Header::decode(&bytes), Err(FrameError::BadPart(3)));
+    }
+
+    #[test]
+    fn test_back_to_back_frames() {
+        let input = Header::new(Kind::Input, 1, None, b"3   4\n4   3\n");
+        let answer = Header::new(Kind::Answer, 1, Some(Part::Two), b"31");
+        let mut bytes = frame(input, b"3   4\n4   3\n");
+        bytes.extend(frame(answer, b"31"));
+
+        let mut buf = [0; 64];
+        let mut decoder = Decoder::new(&mut buf);
+        assert_eq!(
+            decode_all(&mut decoder, &bytes),
+            [
+                Ok((input, b"3   4\n4   3\n".to_vec())),
+                Ok((answer, b"31".to_vec()))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_payload() {
+        let header = Header::new(Kind::Answer, 25, Some(Part::Two), b"");
+        let mut buf = [0; 8];
+        let mut decoder = Decoder::new(&mut buf);
+        assert_eq!(
+            decode_all(&mut decoder, &header.encode()),
+            [Ok((header, Vec::new()))]
+        );
+    }
+
+    #[test]
+    fn test_skips_oversize_payload() {
+        let big = Header::new(Kind::Input, 4, None, &[b'X'; 20]);
+        let small = Header::new(Kind::Input, 3, Some(Part::One), b"mul(2,4)");
+        let mut bytes = frame(big, &[b'X'; 20]);
+        bytes.extend(frame(small, b"mul(2,4)"));
+
+        let mut buf = [0; 16];
+        let mut decoder = Decoder::new(&mut buf);
+        assert_eq!(
+            decode_all(&mut decoder, &bytes),
+            [
+                Err(FrameError::TooLong {
+                    len: 20,
+                    capacity: 16
+                }),
+                Ok((small, b"mul(2,4)".to_vec()))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resyncs_after_noise() {
+        let header = Header::new(Kind::Error, 6, None, b"no solution");
+        let mut bytes = b"\r\n".to_vec();
+        bytes.extend(frame(header, b"no solution"));
+
+        let mut buf = [0; 16];
+        let mut decoder = Decoder::new(&mut buf);
+        assert_eq!(
+            decode_all(&mut decoder, &bytes),
+            [
+                Err(FrameError::UnknownKind(b'\r')),
+                Err(FrameError::UnknownKind(b'\n')),
+                Ok((header, b"no solution".to_vec()))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skips_payload_after_bad_part() {
+        // the payload looks like a frame of its own
+        let inner = Header::new(Kind::Answer, 2, Some(Part::One), b"");
+        let mut bad = frame(
+            Header::new(Kind::Input, 1, None, &inner.encode()),
+            &inner.encode(),
+        );
+        bad[2] = 3;
+        let good = Header::new(Kind::Input, 3, Some(Part::Two), b"do()");
+        let mut bytes = bad;
+        bytes.extend(frame(good, b"do()"));
+
+        let mut buf = [0; 16];
+        let mut decoder = Decoder::new(&mut buf);
+        assert_eq!(
+            decode_all(&mut decoder, &bytes),
+            [Err(FrameError::BadPart(3)), Ok((good, b"do()".to_vec()))]
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Getting answers off the Pico without a debugger session.
+//!
+//! `report_answers` logs two lines through defmt,
+//!
+//! ```text
+//! AOC day=1 part1=11 part2=31 elapsed_us=2048
+//! AOC-USAGE day=1 parse_us=300 part1_us=1500 part2_us=248 stack_bytes=8512
+//! ```
+//!
+//! the first of which `Report::parse` picks back out of the `defmt-print` or
+//! `probe-rs` output on the host. It also leaves a `ResultRecord` in `AOC_RESULT`, which
+//! `memory.x` pins to the start of SRAM4 (0x20040000) so it can be read back
+//! from a halted core.
+
+use crate::timing::Timings;
+use core::fmt::{self, Write};
+
+pub const ANSWER_LEN: usize = 48;
+
+/// An answer rendered to text, cut off at `ANSWER_LEN` bytes.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct AnswerText {
+    len: u8,
+    bytes: [u8; ANSWER_LEN],
+}
+
+impl AnswerText {
+    const EMPTY: Self = Self {
+        len: 0,
+        bytes: [0; ANSWER_LEN],
+    };
+
+    pub fn new(answer: impl fmt::Display) -> Self {
+        let mut text = Self::EMPTY;
+        let _ = write!(text, "{answer}");
+        text
+    }
+
+    pub fn as_str(&self) -> &str {
+        // only ever filled through write_str, which keeps to char boundaries
+        core::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or_default()
+    }
+}
+
+impl Write for AnswerText {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for chr in s.chars() {
+            let start = self.len as usize;
+            let end = start + chr.len_utf8();
+            if end > ANSWER_LEN {
+                return Err(fmt::Error);
+            }
+            chr.encode_utf8(&mut self.bytes[start..end]);
+            self.len = end as u8;
+        }
+        Ok(())
+    }
+}
+
+/// What a finished run leaves behind in RAM.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ResultRecord {
+    /// `ResultRecord::MAGIC` once a report has been written.
+    pub magic: u32,
+    pub day: u32,
+    pub parse_us: u64,
+    pub part1_us: u64,
+    pub part2_us: u64,
+    pub stack_bytes: u32,
+    pub part1: AnswerText,
+    pub part2: AnswerText,
+}
+
+impl ResultRecord {
+    pub const MAGIC: u32 = 0xA0C2_0240;
+
+    pub const EMPTY: Self = Self {
+        magic: 0,
+        day: 0,
+        parse_us: 0,
+        part1_us: 0,
+        part2_us: 0,
+        stack_bytes: 0,
+        part1: AnswerText::EMPTY,
+        part2: AnswerText::EMPTY,
+    };
+
+    pub fn new(
+        day: u8,
+        part1: impl fmt::Display,
+        part2: impl fmt::Display,
+        timings: Timings,
+        stack_bytes: usize,
+    ) -> Self {
+        Self {
+            magic: Self::MAGIC,
+            day: day as u32,
+            parse_us: timings.parse_us,
+            part1_us: timings.part1_us,
+            part2_us: timings.part2_us,
+            stack_bytes: stack_bytes as u32,
+            part1: AnswerText::new(part1),
+            part2: AnswerText::new(part2),
+        }
+    }
+}
+
+/// One `AOC ...` line from the firmware log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Report<'a> {
+    pub day: u8,
+    pub part1: &'a str,
+    pub part2: &'a str,
+    pub elapsed_us: u64,
+}
+
+impl<'a> Report<'a> {
+    /// Finds a report in a log line, skipping whatever timestamp and level
+    /// the log printer put in front of it.
+    pub fn parse(line: &'a str) -> Option<Self> {
+        let (_, fields) = line.split_once("AOC ")?;
+        let mut fields = fields.split_whitespace();
+
+        let mut field = |name: &str| {
+            fields
+                .next()
+                .and_then(|field| field.strip_prefix(name))
+                .and_then(|field| field.strip_prefix('='))
+        };
+
+        Some(Self {
+            day: field("day")?.parse().ok()?,
+            part1: field("part1")?,
+            part2: field("part2")?,
+            elapsed_us: field("elapsed_us")?.parse().ok()?,
+        })
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[unsafe(no_mangle)]
+#[unsafe(link_section = ".aoc_result")]
+pub static mut AOC_RESULT: ResultRecord = ResultRecord::EMPTY;
+
+/// Logs the answers in the format `Report::parse` reads, along with where
+/// the time and stack went, and leaves it all in `AOC_RESULT`.
+#[cfg(feature = "defmt")]
+pub fn report_answers(
+    day: u8,
+    part1: impl fmt::Display,
+    part2: impl fmt::Display,
+    timings: Timings,
+    stack_bytes: usize,
+) {
+    let record = ResultRecord::new(day, part1, part2, timings, stack_bytes);
+
+    defmt::info!(
+        "AOC day={=u8} part1={=str} part2={=str} elapsed_us={=u64}",
+        day,
+        record.part1.as_str(),
+        record.part2.as_str(),
+        timings.total_us()
+    );
+    defmt::info!(
+        "AOC-USAGE day={=u8} parse_us={=u64} part1_us={=u64} part2_us={=u64} stack_bytes={=usize}",
+        day,
+        timings.parse_us,
+        timings.part1_us,
+        timings.part2_us,
+        stack_bytes
+    );
+
+    // volatile so the store survives even though nothing in the program
+    // reads it back
+    unsafe { (&raw mut AOC_RESULT).write_volatile(record) };
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn test_answer_text() {
+        assert_eq!(AnswerText::new(55312u64).as_str(), "55312");
+        assert_eq!(
+            AnswerText::new("4,6,3,5,6,3,5,2,1,0").as_str(),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+    }
+
+    #[test]
+    fn test_answer_text_cut_off() {
+        let long = "é".repeat(ANSWER_LEN);
+        let text = AnswerText::new(long.as_str());
+        assert_eq!(text.as_str(), &long[..ANSWER_LEN]);
+    }
+
+    #[test]
+    fn test_record() {
+        let timings = Timings {
+            parse_us: 10,
+            part1_us: 1500,
+            part2_us: 0,
+        };
+        let record = ResultRecord::new(11, 55312, "", timings, 4096);
+        assert_eq!(record.magic, ResultRecord::MAGIC);
+        assert_eq!(record.day, 11);
+        assert_eq!(record.part1_us, 1500);
+        assert_eq!(record.stack_bytes, 4096);
+        assert_eq!(record.part1.as_str(), "55312");
+        assert_eq!(record.part2.as_str(), "");
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = Report {
+            day: 1,
+            part1: "11",
+            part2: "31",
+            elapsed_us: 2048,
+        };
+
+        assert_eq!(
+            Report::parse("AOC day=1 part1=11 part2=31 elapsed_us=2048"),
+            Some(expected)
+        );
+        assert_eq!(
+            Report::parse("0.123456 INFO  AOC day=1 part1=11 part2=31 elapsed_us=2048"),
+            Some(expected)
+        );
+        assert_eq!(
+            Report::parse(
+                "[INFO ] AOC day=1 part1=11 part2=31 elapsed_us=2048 (r01 src/main.rs:30)"
+            ),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_parse_other_lines() {
+        assert_eq!(Report::parse("INFO  Program start"), None);
+        assert_eq!(Report::parse("AOC day=1 part1=11"), None);
+        assert_eq!(
+            Report::parse("AOC day=x part1=11 part2=31 elapsed_us=2"),
+            None
+        );
+    }
+}
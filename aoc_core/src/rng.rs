@@ -0,0 +1,79 @@
+//! A small seeded random number generator for making up puzzle inputs.
+//!
+//! SplitMix64: not much of a generator, but it is a few lines, needs no
+//! dependencies, and the same seed gives the same input on any machine.
+
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `low..=high`.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        // the modulo bias is far too small to matter for test inputs
+        low + self.next_u64() % (high - low + 1)
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64().is_multiple_of(n)
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.range(0, idx as u64) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_numbers() {
+        let mut rng1 = Rng::new(7);
+        let mut rng2 = Rng::new(7);
+        for _ in 0..100 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let value = rng.range(10, 15);
+            assert!((10..=15).contains(&value));
+            seen[value as usize - 10] = true;
+        }
+        assert_eq!(seen, [true; 6]);
+    }
+
+    #[test]
+    fn test_shuffle_keeps_items() {
+        let mut rng = Rng::new(3);
+        let mut items = [1, 2, 3, 4, 5, 6, 7, 8];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+}
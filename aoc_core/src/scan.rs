@@ -0,0 +1,356 @@
+//! Reading puzzle inputs a byte at a time, for the firmware.
+//!
+//! A `Scanner` walks the whole input rather than one line, straight out of
+//! the `&[u8]` in flash. There is no UTF-8 to check or decode, and numbers
+//! are built up digit by digit rather than copied out and handed to
+//! `str::parse`. Mistakes come back as the same `ParseError`s a `Cursor`
+//! gives, with columns counted in bytes, which for the ASCII the puzzles are
+//! written in is the same thing. Lines end at a `\n`; inputs that came in
+//! with `\r\n` need normalising first.
+
+use crate::parse::{Expected, Found, ParseError};
+
+/// Reads an input from start to end, keeping track of the line it is on.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+    line_start: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self::on_line(1, input)
+    }
+
+    /// A scanner for input that starts on `line`, for when it arrives a
+    /// line or so at a time.
+    pub fn on_line(line: usize, input: &'a [u8]) -> Self {
+        Self {
+            bytes: input,
+            pos: 0,
+            line,
+            line_start: 0,
+        }
+    }
+
+    /// 1-based, like the column.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.pos - self.line_start + 1
+    }
+
+    /// What is left of the input, from here to the end.
+    pub fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+
+    /// Whether the whole input has been read.
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    /// Whether the current line has been read, up to its newline.
+    pub fn at_line_end(&self) -> bool {
+        matches!(self.peek(), None | Some(b'\n'))
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// An error at the current position. Running out part way through a
+    /// line counts as its end, like it does for a `Cursor`.
+    pub fn error(&self, expected: Expected) -> ParseError {
+        let found = match self.peek() {
+            Some(b'\n') => Found::EndOfLine,
+            Some(byte) => Found::Char(char::from(byte)),
+            None if self.pos > self.line_start => Found::EndOfLine,
+            None => Found::EndOfInput,
+        };
+        ParseError {
+            line: self.line,
+            column: self.column(),
+            expected,
+            found,
+        }
+    }
+
+    /// Takes the next byte if `valid` likes it. Never takes a newline.
+    pub fn byte(
+        &mut self,
+        valid: impl Fn(u8) -> bool,
+        expected: &'static str,
+    ) -> Result<u8, ParseError> {
+        match self.peek() {
+            Some(byte) if byte != b'\n' && valid(byte) => {
+                self.pos += 1;
+                Ok(byte)
+            }
+            _ => Err(self.error(Expected::Thing(expected))),
+        }
+    }
+
+    /// Takes exactly `text`, or points at the first byte that differs.
+    pub fn tag(&mut self, text: &'static str) -> Result<(), ParseError> {
+        for &want in text.as_bytes() {
+            if self.peek() != Some(want) {
+                return Err(self.error(Expected::Text(text)));
+            }
+            self.pos += 1;
+        }
+        Ok(())
+    }
+
+    /// Takes the bytes `valid` likes, which may be none, stopping at the
+    /// end of the line.
+    pub fn take_while(&mut self, valid: impl Fn(u8) -> bool) -> &'a [u8] {
+        let rest = self.rest();
+        let len = rest
+            .iter()
+            .position(|&byte| byte == b'\n' || !valid(byte))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Takes a decimal number, with a `-` in front if it is negative.
+    pub fn number<T: TryFrom<u64> + TryFrom<i64>>(&mut self) -> Result<T, ParseError> {
+        let start = self.clone();
+        let negative = self.peek() == Some(b'-');
+        let digits = self.rest()[usize::from(negative)..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit());
+        if digits.clone().next().is_none() {
+            return Err(self.error(Expected::Thing("a number")));
+        }
+
+        let mut len = usize::from(negative);
+        let mut magnitude = Some(0u64);
+        for &digit in digits {
+            magnitude = magnitude
+                .and_then(|magnitude| magnitude.checked_mul(10))
+                .and_then(|magnitude| magnitude.checked_add(u64::from(digit - b'0')));
+            len += 1;
+        }
+
+        // the digits are fine, so it must be too big or negative for T
+        let number = magnitude.and_then(|magnitude| {
+            if negative {
+                let magnitude = i64::try_from(magnitude).ok()?;
+                T::try_from(-magnitude).ok()
+            } else {
+                T::try_from(magnitude).ok()
+            }
+        });
+        let number = number.ok_or(start.error(Expected::Thing("a number that fits")))?;
+        self.pos += len;
+        Ok(number)
+    }
+
+    /// Takes one or more spaces.
+    pub fn spaces(&mut self) -> Result<(), ParseError> {
+        if self.take_while(|byte| byte == b' ').is_empty() {
+            return Err(self.error(Expected::Thing("a space")));
+        }
+        Ok(())
+    }
+
+    /// Takes the newline at the end of this line, checking nothing is left
+    /// over before it. The last line doesn't need one.
+    pub fn end_line(&mut self) -> Result<(), ParseError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(b'\n') => {
+                self.pos += 1;
+                self.line += 1;
+                self.line_start = self.pos;
+                Ok(())
+            }
+            Some(_) => Err(self.error(Expected::Thing("the end of the line"))),
+        }
+    }
+
+    /// Whether a section of lines is over, which is at a blank line or the
+    /// end of the input. The blank line is taken.
+    pub fn end_section(&mut self) -> bool {
+        if self.peek() == Some(b'\n') && self.pos == self.line_start {
+            // an empty line always ends cleanly
+            let _ = self.end_line();
+            return true;
+        }
+        self.is_empty()
+    }
+}
+
+/// Checks `input` is nothing but a map of single byte cells that `valid`
+/// likes, with every row as wide as the first, and returns the width and
+/// height. The rows of such a map are `width + 1` bytes apart.
+pub fn grid(
+    input: &[u8],
+    valid: impl Fn(u8) -> bool,
+    expected: &'static str,
+) -> Result<(usize, usize), ParseError> {
+    let mut scan = Scanner::new(input);
+    let mut width = None;
+    let mut height = 0;
+
+    while !scan.at_line_end() {
+        let mut cols = 0;
+        while width.is_none_or(|width| cols < width) && !scan.at_line_end() {
+            scan.byte(&valid, expected)?;
+            cols += 1;
+        }
+
+        match width {
+            None => width = Some(cols),
+            Some(width) if cols < width => return Err(scan.error(Expected::Thing(expected))),
+            Some(_) => {}
+        }
+        scan.end_line()?;
+        height += 1;
+    }
+
+    match width {
+        // the map stopped at a blank line, or the input was blank to begin with
+        Some(width) if scan.is_empty() => Ok((width, height)),
+        _ => Err(scan.error(Expected::Thing(expected))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        let mut scan = Scanner::new(b"p=-12,7 v=x");
+        scan.tag("p=").unwrap();
+        assert_eq!(scan.number::<i32>(), Ok(-12));
+        scan.tag(",").unwrap();
+        assert_eq!(scan.number::<u8>(), Ok(7));
+        scan.tag(" v=").unwrap();
+        assert_eq!(
+            scan.number::<u8>(),
+            Err(ParseError {
+                line: 1,
+                column: 11,
+                expected: Expected::Thing("a number"),
+                found: Found::Char('x'),
+            })
+        );
+    }
+
+    #[test]
+    fn test_number_too_big() {
+        let mut scan = Scanner::new(b"12 300 -1 18446744073709551615 99999999999999999999");
+        assert_eq!(scan.number::<u8>(), Ok(12));
+        scan.spaces().unwrap();
+        let err = scan.number::<u8>().unwrap_err();
+        assert_eq!(
+            (err.column, err.expected),
+            (4, Expected::Thing("a number that fits"))
+        );
+        // nothing was taken
+        assert_eq!(scan.rest()[..3], *b"300");
+
+        assert_eq!(scan.number::<u16>(), Ok(300));
+        scan.spaces().unwrap();
+        assert_eq!(scan.number::<u32>().unwrap_err().column, 8);
+        assert_eq!(scan.number::<i8>(), Ok(-1));
+        scan.spaces().unwrap();
+        assert_eq!(scan.number::<u64>(), Ok(u64::MAX));
+        scan.spaces().unwrap();
+        assert_eq!(scan.number::<u64>().unwrap_err().column, 32);
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut scan = Scanner::new(b"3   4\n4\n");
+        assert_eq!(scan.number::<u32>(), Ok(3));
+        scan.spaces().unwrap();
+        assert_eq!(scan.number::<u32>(), Ok(4));
+        scan.end_line().unwrap();
+        assert_eq!(scan.number::<u32>(), Ok(4));
+        assert_eq!(
+            scan.spaces(),
+            Err(ParseError {
+                line: 2,
+                column: 2,
+                expected: Expected::Thing("a space"),
+                found: Found::EndOfLine,
+            })
+        );
+        scan.end_line().unwrap();
+        assert!(scan.is_empty());
+        assert_eq!(scan.error(Expected::Thing("more")).found, Found::EndOfInput);
+    }
+
+    #[test]
+    fn test_end_line() {
+        let mut scan = Scanner::new(b"12 \n1");
+        scan.number::<u8>().unwrap();
+        let err = scan.end_line().unwrap_err();
+        assert_eq!((err.column, err.found), (3, Found::Char(' ')));
+        scan.spaces().unwrap();
+        scan.end_line().unwrap();
+        scan.number::<u8>().unwrap();
+        // no newline on the last line is fine
+        assert_eq!(scan.end_line(), Ok(()));
+        let err = scan.tag(",").unwrap_err();
+        assert_eq!((err.line, err.column, err.found), (2, 2, Found::EndOfLine));
+    }
+
+    #[test]
+    fn test_byte_stops_at_newline() {
+        let mut scan = Scanner::new(b"ab\nc");
+        assert_eq!(scan.take_while(|_| true), b"ab");
+        assert!(scan.at_line_end());
+        assert!(scan.byte(|_| true, "anything").is_err());
+    }
+
+    #[test]
+    fn test_sections() {
+        let mut scan = Scanner::new(b"a\nb\n\nc\n");
+        let mut lines = 0;
+        while !scan.end_section() {
+            scan.take_while(|byte| byte.is_ascii_lowercase());
+            scan.end_line().unwrap();
+            lines += 1;
+        }
+        assert_eq!(lines, 2);
+        assert_eq!((scan.line(), scan.rest()), (4, &b"c\n"[..]));
+        scan.tag("c").unwrap();
+        scan.end_line().unwrap();
+        assert!(scan.end_section());
+    }
+
+    #[test]
+    fn test_grid() {
+        let valid = |byte| b"#.S".contains(&byte);
+        assert_eq!(grid(b"#.#\n.S.\n", valid, "a cell"), Ok((3, 2)));
+        assert_eq!(grid(b"#.#\n.S.", valid, "a cell"), Ok((3, 2)));
+    }
+
+    #[test]
+    fn test_grid_errors() {
+        let err = |input| grid(input, |byte| b"#.".contains(&byte), "a cell").unwrap_err();
+
+        assert_eq!((err(b"##\n#\n").line, err(b"##\n#\n").column), (2, 2));
+        assert_eq!(err(b"##\n#\n").found, Found::EndOfLine);
+        assert_eq!(
+            (err(b"##\n###\n").column, err(b"##\n###\n").expected),
+            (3, Expected::Thing("the end of the line"))
+        );
+        assert_eq!(
+            (err(b"#x\n").column, err(b"#x\n").found),
+            (2, Found::Char('x'))
+        );
+        assert_eq!(err(b"#.\n\n.#\n").line, 2);
+        assert_eq!(err(b"").found, Found::EndOfInput);
+    }
+}
@@ -0,0 +1,112 @@
+//! Stack high-water mark by painting.
+//!
+//! The free RAM below the stack is filled with `PAINT` before solving; after
+//! it, the lowest word that no longer holds the pattern is as deep as the
+//! stack got. The big `heapless` buffers all live on the stack, so this is
+//! how close a day came to the 256K in `memory.x`.
+
+pub const PAINT: u32 = 0xC0DE_CAFE;
+
+/// Fills `start..end` with `PAINT`.
+///
+/// # Safety
+/// The range has to be valid, aligned memory that nothing is using.
+pub unsafe fn paint(start: *mut u32, end: *mut u32) {
+    let mut word = start;
+    while word < end {
+        unsafe {
+            word.write_volatile(PAINT);
+            word = word.add(1);
+        }
+    }
+}
+
+/// Counts the words at the bottom of `start..end` that still hold `PAINT`.
+///
+/// # Safety
+/// As for `paint`, over a range that was painted.
+pub unsafe fn untouched(start: *const u32, end: *const u32) -> usize {
+    let mut word = start;
+    while word < end && unsafe { word.read_volatile() } == PAINT {
+        word = unsafe { word.add(1) };
+    }
+    unsafe { word.offset_from(start) as usize }
+}
+
+#[cfg(all(target_arch = "arm", target_os = "none"))]
+mod cortex_m {
+    use super::{paint, untouched};
+
+    unsafe extern "C" {
+        // both come from cortex-m-rt's link.x: the end of the statics and the
+        // top of RAM the stack grows down from
+        static mut __sheap: u32;
+        static mut _stack_start: u32;
+    }
+
+    /// Room left under the stack pointer for `paint_stack`'s own frame.
+    const MARGIN: usize = 64;
+
+    fn stack_pointer() -> usize {
+        let sp: usize;
+        unsafe { core::arch::asm!("mov {}, sp", out(reg) sp) };
+        sp
+    }
+
+    /// Paints everything between the statics and the current stack pointer.
+    /// Call it first thing in `main`.
+    pub fn paint_stack() {
+        let start = &raw mut __sheap;
+        let end = (stack_pointer() - MARGIN) as *mut u32;
+        unsafe { paint(start, end) };
+    }
+
+    /// How many bytes deep the stack has been since `paint_stack`.
+    pub fn stack_high_water() -> usize {
+        let start = &raw const __sheap;
+        let top = &raw const _stack_start as usize;
+        let end = (stack_pointer() - MARGIN) as *const u32;
+        let free = unsafe { untouched(start, end) };
+        top - (start as usize + free * 4)
+    }
+}
+
+#[cfg(all(target_arch = "arm", target_os = "none"))]
+pub use self::cortex_m::{paint_stack, stack_high_water};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_untouched() {
+        let mut ram = [0u32; 16];
+        let range = ram.as_mut_ptr_range();
+        unsafe { paint(range.start, range.end) };
+
+        // a stack growing down from the top that got 5 words deep
+        for word in ram[11..].iter_mut() {
+            *word = 0;
+        }
+        let range = ram.as_ptr_range();
+        assert_eq!(unsafe { untouched(range.start, range.end) }, 11);
+    }
+
+    #[test]
+    fn test_never_touched() {
+        let mut ram = [0u32; 8];
+        let range = ram.as_mut_ptr_range();
+        unsafe { paint(range.start, range.end) };
+        let range = ram.as_ptr_range();
+        assert_eq!(unsafe { untouched(range.start, range.end) }, 8);
+    }
+
+    #[test]
+    fn test_stops_at_first_clobbered_word() {
+        let mut ram = [PAINT; 8];
+        ram[2] = 1;
+        ram[5] = 1;
+        let range = ram.as_ptr_range();
+        assert_eq!(unsafe { untouched(range.start, range.end) }, 2);
+    }
+}
@@ -0,0 +1,192 @@
+//! Collections the day solvers are written against, so the same code can run
+//! on fixed `heapless` buffers on the Pico and on the heap on the host.
+//!
+//! The const capacities only bind the `heapless` backing; `Alloc` ignores
+//! them and grows as needed.
+
+use core::hash::{BuildHasher, Hash};
+use core::ops::{Deref, DerefMut};
+
+pub trait List<T>: Default + Deref<Target = [T]> + DerefMut {
+    /// Hands the item back if the list is full.
+    fn push(&mut self, item: T) -> Result<(), T>;
+    fn pop(&mut self) -> Option<T>;
+    fn insert(&mut self, index: usize, item: T) -> Result<(), T>;
+    fn remove(&mut self, index: usize) -> T;
+    fn clear(&mut self);
+}
+
+pub trait Queue<T>: Default {
+    /// Hands the item back if the queue is full.
+    fn push_back(&mut self, item: T) -> Result<(), T>;
+    fn pop_front(&mut self) -> Option<T>;
+    fn is_empty(&self) -> bool;
+}
+
+pub trait Memo<K, V> {
+    /// How many entries fit, `usize::MAX` when it grows on demand.
+    fn capacity(&self) -> usize;
+    fn get(&self, key: &K) -> Option<&V>;
+    /// Hands the entry back if there is no room for it.
+    fn insert(&mut self, key: K, value: V) -> Result<(), (K, V)>;
+}
+
+/// Picks the concrete collections a solver is built with.
+pub trait Storage {
+    type Vec<T, const N: usize>: List<T>;
+    type Deque<T, const N: usize>: Queue<T>;
+}
+
+/// Fixed capacity buffers, as used on the firmware.
+pub struct Heapless;
+
+impl Storage for Heapless {
+    type Vec<T, const N: usize> = heapless::Vec<T, N>;
+    type Deque<T, const N: usize> = heapless::Deque<T, N>;
+}
+
+impl<T, const N: usize> List<T> for heapless::Vec<T, N> {
+    fn push(&mut self, item: T) -> Result<(), T> {
+        heapless::Vec::push(self, item)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        heapless::Vec::pop(self)
+    }
+
+    fn insert(&mut self, index: usize, item: T) -> Result<(), T> {
+        heapless::Vec::insert(self, index, item)
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        heapless::Vec::remove(self, index)
+    }
+
+    fn clear(&mut self) {
+        heapless::Vec::clear(self)
+    }
+}
+
+impl<T, const N: usize> Queue<T> for heapless::Deque<T, N> {
+    fn push_back(&mut self, item: T) -> Result<(), T> {
+        heapless::Deque::push_back(self, item)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        heapless::Deque::pop_front(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        heapless::Deque::is_empty(self)
+    }
+}
+
+impl<K, V, S, const N: usize> Memo<K, V> for heapless::IndexMap<K, V, S, N>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        heapless::IndexMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        heapless::IndexMap::insert(self, key, value).map(|_| ())
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use self::alloc_impls::Alloc;
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::{List, Memo, Queue, Storage};
+    use alloc::collections::{BTreeMap, VecDeque};
+    use alloc::vec::Vec;
+
+    /// Growable heap collections, as used on the host.
+    pub struct Alloc;
+
+    impl Storage for Alloc {
+        type Vec<T, const N: usize> = Vec<T>;
+        type Deque<T, const N: usize> = VecDeque<T>;
+    }
+
+    impl<T> List<T> for Vec<T> {
+        fn push(&mut self, item: T) -> Result<(), T> {
+            Vec::push(self, item);
+            Ok(())
+        }
+
+        fn pop(&mut self) -> Option<T> {
+            Vec::pop(self)
+        }
+
+        fn insert(&mut self, index: usize, item: T) -> Result<(), T> {
+            Vec::insert(self, index, item);
+            Ok(())
+        }
+
+        fn remove(&mut self, index: usize) -> T {
+            Vec::remove(self, index)
+        }
+
+        fn clear(&mut self) {
+            Vec::clear(self)
+        }
+    }
+
+    impl<T> Queue<T> for VecDeque<T> {
+        fn push_back(&mut self, item: T) -> Result<(), T> {
+            VecDeque::push_back(self, item);
+            Ok(())
+        }
+
+        fn pop_front(&mut self) -> Option<T> {
+            VecDeque::pop_front(self)
+        }
+
+        fn is_empty(&self) -> bool {
+            VecDeque::is_empty(self)
+        }
+    }
+
+    impl<K: Ord, V> Memo<K, V> for BTreeMap<K, V> {
+        fn capacity(&self) -> usize {
+            usize::MAX
+        }
+
+        fn get(&self, key: &K) -> Option<&V> {
+            BTreeMap::get(self, key)
+        }
+
+        fn insert(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+            BTreeMap::insert(self, key, value);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Memo<K, V> for std::collections::HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        std::collections::HashMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        std::collections::HashMap::insert(self, key, value);
+        Ok(())
+    }
+}
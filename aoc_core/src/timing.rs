@@ -0,0 +1,111 @@
+//! Splitting a run into parse, part 1 and part 2 times.
+//!
+//! On the Pico the clock is the RP2040 timer peripheral, which already counts
+//! microseconds, so a closure around `Timer::get_counter` is all it takes.
+
+/// A free running microsecond counter.
+pub trait Clock {
+    fn now_us(&self) -> u64;
+}
+
+impl<F: Fn() -> u64> Clock for F {
+    fn now_us(&self) -> u64 {
+        self()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Timings {
+    pub parse_us: u64,
+    pub part1_us: u64,
+    pub part2_us: u64,
+}
+
+impl Timings {
+    pub fn total_us(&self) -> u64 {
+        self.parse_us + self.part1_us + self.part2_us
+    }
+}
+
+pub struct Stopwatch<'a, C: Clock + ?Sized> {
+    clock: &'a C,
+    last: u64,
+}
+
+impl<'a, C: Clock + ?Sized> Stopwatch<'a, C> {
+    pub fn start(clock: &'a C) -> Self {
+        Self {
+            clock,
+            last: clock.now_us(),
+        }
+    }
+
+    /// Time since the start or the previous lap.
+    pub fn lap(&mut self) -> u64 {
+        let now = self.clock.now_us();
+        // the counter is 64 bits of microseconds, it won't wrap on us
+        let lap = now - self.last;
+        self.last = now;
+        lap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    /// Moves on by `step` every time it is read.
+    struct FakeClock {
+        now: Cell<u64>,
+        step: u64,
+    }
+
+    impl Clock for FakeClock {
+        fn now_us(&self) -> u64 {
+            let now = self.now.get();
+            self.now.set(now + self.step);
+            now
+        }
+    }
+
+    #[test]
+    fn test_laps() {
+        let clock = FakeClock {
+            now: Cell::new(1_000),
+            step: 250,
+        };
+        let mut stopwatch = Stopwatch::start(&clock);
+        assert_eq!(stopwatch.lap(), 250);
+        assert_eq!(stopwatch.lap(), 250);
+    }
+
+    #[test]
+    fn test_closure_clock() {
+        let now = Cell::new(0);
+        let clock = || now.get();
+        let mut stopwatch = Stopwatch::start(&clock);
+
+        now.set(40);
+        let parse_us = stopwatch.lap();
+        now.set(1_040);
+        let part1_us = stopwatch.lap();
+        let part2_us = stopwatch.lap();
+
+        let timings = Timings {
+            parse_us,
+            part1_us,
+            part2_us,
+        };
+        assert_eq!(
+            timings,
+            Timings {
+                parse_us: 40,
+                part1_us: 1_000,
+                part2_us: 0
+            }
+        );
+        assert_eq!(timings.total_us(), 1_040);
+    }
+}
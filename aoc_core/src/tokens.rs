@@ -0,0 +1,371 @@
+//! Picking instructions out of corrupted memory with a transition table.
+//!
+//! Each instruction is written as a pattern like `mul({3},{3})`, where
+//! `{N}` stands for a number of 1 to `N` digits and everything else has to
+//! be there as written. `Table::compile` turns a set of them into a table
+//! of states, with the next state for every byte, at compile time when it
+//! is a `static`. The input is read once, a byte at a time, picking up the
+//! numbers as it goes. Patterns sharing a start share its states, so
+//! `do()` and `don't()` both go through the same `do`.
+//!
+//! Where a match breaks off, the byte that broke it leads wherever it would
+//! from the start, so `mul(mul(2,3)` still finds the second `mul`. That's
+//! all it takes to find every match as long as no pattern starts with a
+//! byte found further into one, which `compile` checks.
+//!
+//! A table of `S` states takes `S` * 256 bytes, which on the firmware sits in
+//! flash next to the input.
+
+/// The most numbers one pattern can have.
+pub const MAX_NUMBERS: usize = 4;
+
+/// An instruction found in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    /// The pattern it matched, as it was given to `Table::compile`.
+    pub pattern: &'static str,
+    /// Its numbers, in order, with zeros after the last.
+    pub numbers: [u32; MAX_NUMBERS],
+}
+
+/// A set of patterns compiled into `S` states, the first being where every
+/// match starts.
+#[derive(Debug, Clone)]
+pub struct Table<const S: usize> {
+    /// The state each byte leads to. One that breaks a match leads where it
+    /// would from the start, which is 0 if that's nowhere.
+    next: [[u8; 256]; S],
+    /// Whether a state is the start or the first of a match, so the numbers
+    /// read so far are thrown away on reaching it.
+    fresh: [bool; S],
+    /// Whether a state is reached by a digit of a number.
+    digit: [bool; S],
+    /// The pattern a state completes.
+    accept: [Option<&'static str>; S],
+}
+
+impl<const S: usize> Table<S> {
+    /// Fails to compile if there are more states than `S`, or a pattern is
+    /// one the table can't tell apart from another: one that starts
+    /// another, one that could read a digit as both part of a number and
+    /// not, or one with two numbers in a row. Patterns have to start and
+    /// end in text, and no pattern can start with a digit or with a byte
+    /// that's further into any pattern.
+    pub const fn compile(patterns: &[&'static str]) -> Self {
+        assert!(S <= 256, "states are numbered with a byte");
+        let mut table = Table {
+            next: [[0; 256]; S],
+            fresh: [false; S],
+            digit: [false; S],
+            accept: [None; S],
+        };
+        let mut states = 1;
+
+        let mut p = 0;
+        while p < patterns.len() {
+            let bytes = patterns[p].as_bytes();
+            assert!(!bytes.is_empty(), "an empty pattern matches everywhere");
+            assert!(
+                bytes[0] != b'{' && !bytes[0].is_ascii_digit(),
+                "patterns have to start with text"
+            );
+            // the states the pattern has got to: one after text, or one
+            // for each number of digits after a number
+            let mut from = [0; 9];
+            let mut froms = 1;
+            let mut numbers = 0;
+
+            let mut i = 0;
+            while i < bytes.len() {
+                if bytes[i] == b'{' {
+                    assert!(
+                        i + 2 < bytes.len() && bytes[i + 2] == b'}',
+                        "a number is a digit count in braces"
+                    );
+                    let max_digits = bytes[i + 1].wrapping_sub(b'0') as usize;
+                    assert!(
+                        max_digits >= 1 && max_digits <= 9,
+                        "numbers have 1 to 9 digits"
+                    );
+                    assert!(!table.digit[from[0]], "numbers need text between them");
+                    numbers += 1;
+                    assert!(numbers <= MAX_NUMBERS, "too many numbers");
+
+                    // a chain of states, one per digit read so far, the
+                    // first following on from where the pattern had got to
+                    let mut digits = 0;
+                    while digits < max_digits {
+                        assert!(states < S, "more states than the table has");
+                        let state = states;
+                        states += 1;
+                        table.digit[state] = true;
+                        let mut byte = b'0';
+                        while byte <= b'9' {
+                            if digits == 0 {
+                                let mut f = 0;
+                                while f < froms {
+                                    table.link(from[f], byte, state);
+                                    f += 1;
+                                }
+                            } else {
+                                table.link(state - 1, byte, state);
+                            }
+                            byte += 1;
+                        }
+                        digits += 1;
+                    }
+                    // the number can end after any of its digits
+                    froms = max_digits;
+                    let mut d = 0;
+                    while d < max_digits {
+                        from[d] = states - max_digits + d;
+                        d += 1;
+                    }
+                    i += 3;
+                } else {
+                    let byte = bytes[i] as usize;
+                    let shared = table.next[from[0]][byte];
+                    let mut f = 1;
+                    while f < froms {
+                        assert!(
+                            table.next[from[f]][byte] == shared,
+                            "text after a number could go two ways"
+                        );
+                        f += 1;
+                    }
+
+                    let state = if shared != 0 {
+                        let shared = shared as usize;
+                        assert!(!table.digit[shared], "text could be read as a digit");
+                        assert!(table.accept[shared].is_none(), "one pattern starts another");
+                        shared
+                    } else {
+                        assert!(states < S, "more states than the table has");
+                        let state = states;
+                        states += 1;
+                        let mut f = 0;
+                        while f < froms {
+                            table.link(from[f], byte as u8, state);
+                            f += 1;
+                        }
+                        state
+                    };
+                    from[0] = state;
+                    froms = 1;
+                    i += 1;
+                }
+            }
+
+            let end = from[0];
+            assert!(
+                froms == 1 && !table.digit[end],
+                "patterns have to end in text"
+            );
+            let mut byte = 0;
+            while byte < 256 {
+                assert!(table.next[end][byte] == 0, "one pattern starts another");
+                byte += 1;
+            }
+            table.accept[end] = Some(patterns[p]);
+            p += 1;
+        }
+
+        // no match can start inside another, only at the byte that broke it
+        let mut p = 0;
+        while p < patterns.len() {
+            let bytes = patterns[p].as_bytes();
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] == b'{' {
+                    i += 3;
+                    continue;
+                }
+                assert!(
+                    table.next[0][bytes[i] as usize] == 0,
+                    "a pattern could start inside another"
+                );
+                i += 1;
+            }
+            p += 1;
+        }
+
+        table.fresh[0] = true;
+        let mut byte = 0;
+        while byte < 256 {
+            table.fresh[table.next[0][byte] as usize] = true;
+            byte += 1;
+        }
+        let mut state = 1;
+        while state < states {
+            let mut byte = 0;
+            while byte < 256 {
+                if table.next[state][byte] == 0 && table.accept[state].is_none() {
+                    table.next[state][byte] = table.next[0][byte];
+                }
+                byte += 1;
+            }
+            state += 1;
+        }
+
+        table
+    }
+
+    const fn link(&mut self, from: usize, byte: u8, to: usize) {
+        let next = &mut self.next[from][byte as usize];
+        assert!(*next == 0, "a digit could go two ways");
+        *next = to as u8;
+    }
+
+    /// The instruction starting right at the beginning of `bytes`, if any.
+    pub fn token_at(&self, bytes: &[u8]) -> Option<Token> {
+        let mut reader = Reader::new(self);
+        for (i, &byte) in bytes.iter().enumerate() {
+            // back at the start, or starting another match
+            let next = self.next[reader.state][usize::from(byte)];
+            if next == 0 || (i > 0 && self.fresh[usize::from(next)]) {
+                return None;
+            }
+            if let Some(token) = reader.read(byte) {
+                return Some(token);
+            }
+        }
+
+        None
+    }
+
+    /// Every instruction in `input`, in order, reading each byte once.
+    pub fn tokens<'a>(&'a self, input: &'a [u8]) -> impl Iterator<Item = Token> + 'a {
+        let mut reader = Reader::new(self);
+        input.iter().filter_map(move |&byte| reader.read(byte))
+    }
+}
+
+/// Where a table has got to in its input.
+struct Reader<'a, const S: usize> {
+    table: &'a Table<S>,
+    state: usize,
+    numbers: [u32; MAX_NUMBERS],
+    number: usize,
+}
+
+impl<'a, const S: usize> Reader<'a, S> {
+    fn new(table: &'a Table<S>) -> Self {
+        Self {
+            table,
+            state: 0,
+            numbers: [0; MAX_NUMBERS],
+            number: 0,
+        }
+    }
+
+    /// Moves on over `byte`, handing back the instruction it completes.
+    fn read(&mut self, byte: u8) -> Option<Token> {
+        let table = self.table;
+        let next = usize::from(table.next[self.state][usize::from(byte)]);
+        if table.fresh[next] {
+            self.numbers = [0; MAX_NUMBERS];
+            self.number = 0;
+        }
+        if table.digit[next] {
+            // at most 9 digits, so this can't overflow
+            self.numbers[self.number] = self.numbers[self.number] * 10 + u32::from(byte - b'0');
+        } else if table.digit[self.state] && !table.fresh[next] {
+            self.number += 1;
+        }
+        self.state = next;
+
+        let pattern = table.accept[next]?;
+        self.state = 0;
+        Some(Token {
+            pattern,
+            numbers: self.numbers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TABLE: Table<32> = Table::compile(&["mul({3},{3})", "do()", "don't()", "sub({1}-{2})"]);
+
+    #[test]
+    fn test_tokens() {
+        let mut tokens = TABLE.tokens(b"xmul(2,4)%don't()_do(mul(11,8)sub(1-23)");
+        let token = |pattern, numbers| Some(Token { pattern, numbers });
+        assert_eq!(tokens.next(), token("mul({3},{3})", [2, 4, 0, 0]));
+        assert_eq!(tokens.next(), token("don't()", [0; 4]));
+        assert_eq!(tokens.next(), token("mul({3},{3})", [11, 8, 0, 0]));
+        assert_eq!(tokens.next(), token("sub({1}-{2})", [1, 23, 0, 0]));
+        assert_eq!(tokens.next(), None);
+    }
+
+    #[test]
+    fn test_restart() {
+        let tokens = |input| TABLE.tokens(input).map(|token| token.pattern);
+        assert!(tokens(b"mul(mul(2,3)").eq(["mul({3},{3})"]));
+        assert!(tokens(b"do(don't()sub(1-2d)").eq(["don't()"]));
+        assert_eq!(
+            TABLE
+                .tokens(b"mul(12,mul(3,4)")
+                .next()
+                .map(|token| token.numbers),
+            Some([3, 4, 0, 0])
+        );
+        assert_eq!(TABLE.token_at(b"mul(mul(2,3)"), None);
+    }
+
+    #[test]
+    fn test_digit_limits() {
+        assert_eq!(TABLE.token_at(b"mul(1234,5)"), None);
+        assert_eq!(TABLE.token_at(b"mul(,5)"), None);
+        assert_eq!(TABLE.token_at(b"sub(12-3)"), None);
+        assert_eq!(
+            TABLE.token_at(b"mul(999,1)").map(|token| token.numbers),
+            Some([999, 1, 0, 0])
+        );
+    }
+
+    #[test]
+    fn test_shared_states() {
+        // mul( ... ) takes 13 states with the start, do() 4 and don't()
+        // the 5 after do
+        let _: Table<22> = Table::compile(&["mul({3},{3})", "do()", "don't()"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "more states than the table has")]
+    fn test_too_few_states() {
+        let _: Table<21> = Table::compile(&["mul({3},{3})", "do()", "don't()"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "one pattern starts another")]
+    fn test_prefix() {
+        let _: Table<8> = Table::compile(&["do()", "do"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a digit could go two ways")]
+    fn test_ambiguous_number() {
+        let _: Table<16> = Table::compile(&["a{2}b", "a{1}c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a pattern could start inside another")]
+    fn test_start_inside() {
+        let _: Table<16> = Table::compile(&["add()", "do()"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "patterns have to start with text")]
+    fn test_number_at_start() {
+        let _: Table<8> = Table::compile(&["{2}a"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "patterns have to end in text")]
+    fn test_number_at_end() {
+        let _: Table<8> = Table::compile(&["a{2}"]);
+    }
+}
@@ -0,0 +1,248 @@
+//! Baking puzzle inputs into firmware.
+//!
+//! The C projects `#include "inputs.h"`, which `Header` writes and the
+//! `cinputs` tool wraps. The Rust firmware crates call `embed_input` from
+//! their `build.rs` and `include!` what it writes. Either way the input is
+//! normalised first, so a hand-copied file with `\r\n`s or a missing last
+//! newline parses the same as one straight from the site.
+
+use std::fmt::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// `\n` line endings, with exactly one after the last line. Nothing but
+/// whitespace normalises to nothing.
+pub fn normalise(input: &str) -> String {
+    let mut input = input.replace("\r\n", "\n");
+    input.truncate(input.trim_end().len());
+    if !input.is_empty() {
+        input.push('\n');
+    }
+    input
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Style {
+    /// A string literal, a line of input to a line of C.
+    #[default]
+    String,
+    /// An array of bytes, for compilers that choke on long literals.
+    Bytes,
+}
+
+/// An `inputs.h`, with a NUL terminated `char` array per input and a
+/// `<NAME>_LEN` for each that doesn't count the NUL.
+#[derive(Debug, Clone, Default)]
+pub struct Header {
+    style: Style,
+    lines: bool,
+    arrays: Vec<(String, String)>,
+}
+
+impl Header {
+    pub fn new(style: Style) -> Self {
+        Self {
+            style,
+            ..Self::default()
+        }
+    }
+
+    /// Also gives each input a `<name>_lines` table of where each line
+    /// starts, and `<NAME>_LINES` saying how many there are.
+    pub fn lines(mut self) -> Self {
+        self.lines = true;
+        self
+    }
+
+    /// Adds `text` as `name`, normalised. Anything in `name` that can't be
+    /// in a C identifier becomes `_`.
+    pub fn array(mut self, name: &str, text: &str) -> Self {
+        let name = name.replace(|chr: char| !chr.is_ascii_alphanumeric(), "_");
+        self.arrays.push((name, normalise(text)));
+        self
+    }
+}
+
+/// Escapes a line for a C string literal. Octal escapes are used since,
+/// unlike `\x`, they can't run on into a following digit.
+fn c_literal(line: &str) -> String {
+    let mut literal = String::from("\"");
+    for &byte in line.as_bytes() {
+        match byte {
+            b'\n' => literal.push_str("\\n"),
+            b'"' | b'\\' | b'?' => {
+                literal.push('\\');
+                literal.push(byte as char);
+            }
+            b' '..=b'~' => literal.push(byte as char),
+            _ => write!(literal, "\\{byte:03o}").unwrap(),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "// Generated by cinputs, edit the inputs rather than this."
+        )?;
+        writeln!(f, "#ifndef INPUTS_H")?;
+        writeln!(f, "#define INPUTS_H")?;
+
+        for (name, text) in &self.arrays {
+            let upper = name.to_ascii_uppercase();
+            writeln!(f)?;
+            writeln!(f, "#define {upper}_LEN {}", text.len())?;
+
+            match self.style {
+                Style::String if text.is_empty() => writeln!(f, "static char {name}[] = \"\";")?,
+                Style::String => {
+                    write!(f, "static char {name}[] =")?;
+                    for line in text.split_inclusive('\n') {
+                        write!(f, "\n    {}", c_literal(line))?;
+                    }
+                    writeln!(f, ";")?;
+                }
+                Style::Bytes => {
+                    writeln!(f, "static char {name}[{upper}_LEN + 1] = {{")?;
+                    for chunk in text.as_bytes().chunks(16) {
+                        let bytes: Vec<_> =
+                            chunk.iter().map(|byte| format!("0x{byte:02x},")).collect();
+                        writeln!(f, "    {}", bytes.join(" "))?;
+                    }
+                    writeln!(f, "    0x00,")?;
+                    writeln!(f, "}};")?;
+                }
+            }
+
+            if self.lines {
+                let starts: Vec<_> = text
+                    .split_inclusive('\n')
+                    .scan(0, |start, line| {
+                        let this = *start;
+                        *start += line.len();
+                        Some(this.to_string())
+                    })
+                    .collect();
+                writeln!(f, "#define {upper}_LINES {}", starts.len())?;
+                writeln!(
+                    f,
+                    "static const unsigned int {name}_lines[{upper}_LINES + 1] = {{{}}};",
+                    // one past the end, so line i is lines[i]..lines[i + 1]
+                    starts
+                        .into_iter()
+                        .chain([text.len().to_string()])
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )?;
+            }
+        }
+
+        writeln!(f)?;
+        writeln!(f, "#endif")
+    }
+}
+
+/// For a firmware crate's `build.rs`. Reads `path`, relative to the crate,
+/// and writes it normalised to `$OUT_DIR/input.rs` as
+///
+/// ```text
+/// pub static INPUT: &[u8] = b"...";
+/// ```
+///
+/// for `src/main.rs` to `include!(concat!(env!("OUT_DIR"), "/input.rs"))`.
+pub fn embed_input(path: &str) {
+    let dir = std::env::var_os("CARGO_MANIFEST_DIR").expect("run from a build script");
+    let out = std::env::var_os("OUT_DIR").expect("run from a build script");
+    println!("cargo:rerun-if-changed={path}");
+
+    let path = Path::new(&dir).join(path);
+    let input = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "could not read {}: {err}\nfetch it with `aoc input --day <day> --rust .`",
+            path.display()
+        )
+    });
+    std::fs::write(PathBuf::from(out).join("input.rs"), rust_input(&input)).unwrap();
+}
+
+fn rust_input(input: &str) -> String {
+    let input = normalise(input);
+    format!(
+        "/// The puzzle input, with `\\n` line endings and one after the last line.\n\
+         pub static INPUT: &[u8] = b\"{}\";\n",
+        input.as_bytes().escape_ascii()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(normalise("3   4\r\n4   3"), "3   4\n4   3\n");
+        assert_eq!(normalise("3   4\n\n\n"), "3   4\n");
+        assert_eq!(normalise(" \r\n"), "");
+    }
+
+    #[test]
+    fn test_string_header() {
+        let header = Header::new(Style::String)
+            .lines()
+            .array("input", "ab\n\"c?\\\u{7}")
+            .array("example-1", "");
+        assert_eq!(
+            header.to_string(),
+            r#"// Generated by cinputs, edit the inputs rather than this.
+#ifndef INPUTS_H
+#define INPUTS_H
+
+#define INPUT_LEN 9
+static char input[] =
+    "ab\n"
+    "\"c\?\\\007\n";
+#define INPUT_LINES 2
+static const unsigned int input_lines[INPUT_LINES + 1] = {0, 3, 9};
+
+#define EXAMPLE_1_LEN 0
+static char example_1[] = "";
+#define EXAMPLE_1_LINES 0
+static const unsigned int example_1_lines[EXAMPLE_1_LINES + 1] = {0};
+
+#endif
+"#
+        );
+    }
+
+    #[test]
+    fn test_bytes_header() {
+        let header = Header::new(Style::Bytes).array("input", "0123456789abcdef\nx");
+        assert_eq!(
+            header.to_string(),
+            "// Generated by cinputs, edit the inputs rather than this.
+#ifndef INPUTS_H
+#define INPUTS_H
+
+#define INPUT_LEN 19
+static char input[INPUT_LEN + 1] = {
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x0a, 0x78, 0x0a,
+    0x00,
+};
+
+#endif
+"
+        );
+    }
+
+    #[test]
+    fn test_rust_input() {
+        assert_eq!(
+            rust_input("a\"b\r\n\\"),
+            "/// The puzzle input, with `\\n` line endings and one after the last line.\n\
+             pub static INPUT: &[u8] = b\"a\\\"b\\n\\\\\\n\";\n"
+        );
+    }
+}
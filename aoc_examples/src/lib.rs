@@ -0,0 +1,137 @@
+//! The puzzle examples in `examples/` and the answers they should give, as
+//! listed in `examples/answers.toml`.
+
+use aoc_core::Setup;
+use serde::Deserialize;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    pub day: u8,
+    /// Path of the input, relative to `examples/`
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    /// Takes too long without optimisations for a plain `cargo test`
+    #[serde(default)]
+    pub slow: bool,
+    /// Sizes and limits the example has smaller than a real input's
+    #[serde(default)]
+    pub setup: Setup,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Answers {
+    example: Vec<Example>,
+}
+
+impl Example {
+    /// `day12/e-shape.txt` becomes `day12_e_shape`.
+    pub fn name(&self) -> String {
+        let name = self.input.strip_suffix(".txt").unwrap_or(&self.input);
+        name.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+    }
+
+    pub fn path(&self) -> PathBuf {
+        dir().join(&self.input)
+    }
+
+    pub fn read(&self) -> String {
+        let path = self.path();
+        std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("could not read {}: {err}", path.display()))
+    }
+
+    /// The known answer for part 1 or 2, if there is one.
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+pub fn dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples")
+}
+
+/// Every example in `answers.toml`.
+pub fn load() -> Vec<Example> {
+    let path = dir().join("answers.toml");
+    let text = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("could not read {}: {err}", path.display()));
+    let answers: Answers =
+        toml::from_str(&text).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+    answers.example
+}
+
+/// Writes a `#[test]` for every known answer, calling `check` as
+/// `check(day, input_path, part, answer, &setup)`, for an integration test
+/// to `include!` with `aoc_core::Setup` in scope. Names get `suffix` on the end, so several `check`s can be
+/// generated into one file. Only days that `filter` keeps get a test.
+pub fn generate_tests(out: &mut String, check: &str, suffix: &str, filter: impl Fn(u8) -> bool) {
+    for example in load().iter().filter(|example| filter(example.day)) {
+        for part in [1, 2] {
+            let Some(answer) = example.answer(part) else {
+                continue;
+            };
+            let ignore = if example.slow {
+                "#[ignore = \"slow in a debug build, run with --release -- --ignored\"]\n"
+            } else {
+                ""
+            };
+            writeln!(
+                out,
+                "#[test]\n{ignore}fn {}_part{part}{suffix}() {{\n    \
+                 {check}({}, {:?}, {part}, {answer:?}, &{:?});\n}}\n",
+                example.name(),
+                example.day,
+                example.path(),
+                example.setup,
+            )
+            .unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_input_exists() {
+        let examples = load();
+        assert!(!examples.is_empty());
+        for example in examples {
+            assert!(example.path().is_file(), "{}", example.input);
+        }
+    }
+
+    #[test]
+    fn test_names_are_unique() {
+        let mut names: Vec<_> = load().iter().map(Example::name).collect();
+        let count = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), count);
+    }
+
+    #[test]
+    fn test_name() {
+        let example = Example {
+            day: 12,
+            input: "day12/e-shape.txt".into(),
+            part1: None,
+            part2: Some("236".into()),
+            slow: false,
+            setup: Setup::default(),
+        };
+        assert_eq!(example.name(), "day12_e_shape");
+        assert_eq!(example.answer(1), None);
+        assert_eq!(example.answer(2), Some("236"));
+    }
+}
@@ -0,0 +1,246 @@
+//! The days the firmware can solve, behind one entry point so every image
+//! that takes its input at runtime dispatches the same way.
+
+#![no_std]
+
+use aoc_core::error::InputError;
+use aoc_core::storage::Heapless;
+use aoc_core::timing::{Clock, Stopwatch, Timings};
+use aoc_grid::Pos;
+use core::fmt::{Display, Write};
+use core::mem::size_of;
+use heapless::{Deque, FnvIndexMap, String, Vec};
+
+/// The days `solve` knows.
+pub const DAYS: [u8; 9] = [1, 2, 3, 4, 5, 7, 8, 10, 11];
+
+pub const ANSWER_LEN: usize = 80;
+/// Enough for the day 11 example; FnvIndexMap wants a power of two
+pub const MAXMEMO: usize = 4096;
+
+pub type Answer = String<ANSWER_LEN>;
+
+/// Renders an answer, or an error for the host to show.
+pub fn text(value: impl Display) -> Answer {
+    let mut answer = Answer::new();
+    // only long error messages get near ANSWER_LEN, and a cut off one still
+    // beats none
+    let _ = write!(answer, "{value}");
+    answer
+}
+
+/// Solves both parts of `day` with fixed capacity storage, or says why not.
+pub fn solve(day: u8, input: &[u8]) -> Result<(Answer, Answer), Answer> {
+    let solved = solve_timed(day, input, &|| 0)
+        .ok_or_else(|| text(format_args!("no solution for day {day}")))?;
+    let (ans1, ans2, _) = solved.map_err(text)?;
+    Ok((ans1, ans2))
+}
+
+/// `solve`, timing each step on `clock`, or `None` for a day not in
+/// `DAYS`. The days that parse as they go have no parse time, and count it
+/// in the parts instead.
+pub fn solve_timed<C: Clock + ?Sized>(
+    day: u8,
+    input: &[u8],
+    clock: &C,
+) -> Option<Result<(Answer, Answer, Timings), InputError>> {
+    DAYS.contains(&day).then(|| timed(day, input, clock))
+}
+
+fn timed<C: Clock + ?Sized>(
+    day: u8,
+    input: &[u8],
+    clock: &C,
+) -> Result<(Answer, Answer, Timings), InputError> {
+    let mut stopwatch = Stopwatch::start(clock);
+    let mut timings = Timings::default();
+
+    let answers = match day {
+        1 => {
+            let (left, right) = r01_lib::process_inputs::<Heapless>(input)?;
+            timings.parse_us = stopwatch.lap();
+            let ans1 = r01_lib::part1(&left, &right);
+            timings.part1_us = stopwatch.lap();
+            let ans2 = r01_lib::part2(&left, &right);
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        2 => {
+            use r02_lib::{Mode, count_safe_levels};
+            let ans1 = count_safe_levels::<Heapless>(input, Mode::Intolerant)?;
+            timings.part1_us = stopwatch.lap();
+            let ans2 = count_safe_levels::<Heapless>(input, Mode::Tolerant)?;
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        3 => {
+            let ans1 = r03_lib::part1(input);
+            timings.part1_us = stopwatch.lap();
+            let ans2 = r03_lib::part2(input);
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        4 => {
+            let map = r04_lib::read_map(input)?;
+            timings.parse_us = stopwatch.lap();
+            let ans1 = r04_lib::part1(&map, "XMAS");
+            timings.part1_us = stopwatch.lap();
+            let ans2 = r04_lib::part2(&map, "MAS");
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        5 => {
+            use r05_lib::{Mode, check_updates};
+            let ans1 = check_updates::<Heapless>(input, Mode::DontFix)?;
+            timings.part1_us = stopwatch.lap();
+            let ans2 = check_updates::<Heapless>(input, Mode::FixUpdates)?;
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        7 => {
+            use r07_lib::{Mode, calibrate};
+            let ans1 = calibrate::<Heapless>(input, Mode::AddMul)?;
+            timings.part1_us = stopwatch.lap();
+            let ans2 = calibrate::<Heapless>(input, Mode::Concat)?;
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        8 => {
+            let antennas = r08_lib::Antennas::new(input)?;
+            timings.parse_us = stopwatch.lap();
+            let ans1 = antennas.part1::<Heapless>()?;
+            timings.part1_us = stopwatch.lap();
+            let ans2 = antennas.part2::<Heapless>()?;
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        10 => {
+            let map: r10_lib::Map<Heapless, _> = r10_lib::Map::new(input)?;
+            timings.parse_us = stopwatch.lap();
+            let ans1 = map.part1()?;
+            timings.part1_us = stopwatch.lap();
+            let ans2 = map.part2()?;
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        11 => {
+            let mut memo: FnvIndexMap<r11_lib::Key, u64, MAXMEMO> = FnvIndexMap::new();
+            let ans1 = r11_lib::blink_stones(input, 25, &mut memo)?;
+            timings.part1_us = stopwatch.lap();
+            let mut memo: FnvIndexMap<r11_lib::Key, u64, MAXMEMO> = FnvIndexMap::new();
+            let ans2 = r11_lib::blink_stones(input, 75, &mut memo)?;
+            timings.part2_us = stopwatch.lap();
+            (text(ans1), text(ans2))
+        }
+        _ => unreachable!("day {day} isn't in DAYS"),
+    };
+
+    Ok((answers.0, answers.1, timings))
+}
+
+/// Bytes of RAM taken by the buffers `day` keeps while it solves, going by
+/// their types on whatever this was built for, so `usize`s count double on
+/// a 64-bit host. The input stays where it is and isn't counted, and nor is
+/// the stack the solving itself uses, which `stack_high_water` measures on
+/// the board.
+pub fn ram(day: u8) -> Option<usize> {
+    let bytes = match day {
+        // both columns whole, as they can't be sorted otherwise, and the
+        // location IDs have five digits, too many for a u16
+        1 => 2 * size_of::<r01_lib::Column<Heapless>>(),
+        // the last couple of levels and the cheapest ways to keep them
+        2 => size_of::<r02_lib::Checker<Heapless>>(),
+        // the instructions are matched where they lie, by tables in flash
+        3 => 0,
+        4 => size_of::<r04_lib::Map<&[u8]>>(),
+        5 => size_of::<r05_lib::DepList>() + size_of::<Vec<u8, { r05_lib::MAX_UPDATES }>>(),
+        7 => size_of::<Vec<r07_lib::Term, { r07_lib::MAX_TERMS }>>(),
+        8 => {
+            size_of::<r08_lib::Antennas<&[u8]>>()
+                + size_of::<Vec<(i8, i8), { r08_lib::MAX_NODES }>>()
+        }
+        10 => {
+            size_of::<r10_lib::Map<Heapless, &[u8]>>()
+                + size_of::<Deque<Pos, { r10_lib::MAX_TASKS }>>()
+                + size_of::<Vec<Pos, { r10_lib::MAX_DEST }>>()
+        }
+        11 => size_of::<FnvIndexMap<r11_lib::Key, u64, MAXMEMO>>(),
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    fn solved(day: u8, input: &[u8]) -> (Answer, Answer) {
+        solve(day, input).unwrap()
+    }
+
+    #[test]
+    fn test_day01() {
+        let input = b"3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(solved(1, input), (text(11), text(31)));
+    }
+
+    #[test]
+    fn test_day11() {
+        assert_eq!(
+            solved(11, b"125 17\n"),
+            (text(55312), text(65601038650482u64))
+        );
+    }
+
+    #[test]
+    fn test_capacity_error() {
+        let mut input = std::string::String::from("3: 1 2\n31:");
+        input.extend((0..r07_lib::MAX_TERMS + 1).map(|_| " 1"));
+        assert_eq!(
+            solve(7, input.as_bytes()),
+            Err(text("terms is full (30 entries) at input line 2"))
+        );
+        // counting day 2's safe reports never keeps their levels
+        assert!(solve(2, b"1 2 3 4 5 6 7 8 9 10 11\n").is_ok());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            solve(7, b"190: 10 19\n3267 40 27\n"),
+            Err(text("line 2, column 5: expected \":\", found ' '"))
+        );
+    }
+
+    #[test]
+    fn test_timed() {
+        // every reading of this clock is a microsecond on from the last
+        let now = core::cell::Cell::new(0);
+        let clock = || {
+            now.set(now.get() + 1);
+            now.get()
+        };
+        let (_, _, timings) = solve_timed(4, b"XMAS\nSAMX\n", &clock).unwrap().unwrap();
+        assert_eq!(timings.total_us(), 3);
+        let (_, _, timings) = solve_timed(3, b"mul(2,4)\n", &clock).unwrap().unwrap();
+        assert_eq!(timings.parse_us, 0);
+        assert!(solve_timed(6, b"", &clock).is_none());
+    }
+
+    #[test]
+    fn test_ram() {
+        // the word search is read where it lies
+        assert!(ram(4).unwrap() < 64);
+        assert_eq!(ram(3), Some(0));
+        assert_eq!(ram(6), None);
+        assert!(DAYS.iter().all(|&day| ram(day).is_some()));
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        assert_eq!(solve(6, b""), Err(text("no solution for day 6")));
+    }
+}
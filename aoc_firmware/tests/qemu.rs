@@ -0,0 +1,67 @@
+//! Runs the `rqemu` image under `qemu-system-arm` against the puzzle
+//! examples in `examples/`. Needs QEMU and the `thumbv7m-none-eabi` target,
+//! so it only runs when asked for: `cargo test --test qemu -- --ignored`.
+
+use aoc_core::report::Report;
+use aoc_firmware::DAYS;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const QEMU: &str = "qemu-system-arm";
+
+fn rqemu_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../rqemu")
+}
+
+fn build() -> PathBuf {
+    // run from the crate so its .cargo/config.toml picks the target
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--release"])
+        .current_dir(rqemu_dir())
+        .status()
+        .unwrap();
+    assert!(status.success(), "building rqemu failed");
+
+    rqemu_dir().join("target/thumbv7m-none-eabi/release/rqemu")
+}
+
+fn run(elf: &Path, day: u8, input: &Path) -> String {
+    let semihosting = format!(
+        "enable=on,target=native,arg=rqemu,arg={day},arg={}",
+        input.display()
+    );
+    let output = Command::new(QEMU)
+        .args(["-cpu", "cortex-m3", "-machine", "mps2-an385", "-nographic"])
+        .args(["-semihosting-config", &semihosting, "-kernel"])
+        .arg(elf)
+        .output()
+        .unwrap_or_else(|err| panic!("could not run {QEMU}: {err}"));
+
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    assert!(output.status.success(), "day {day} failed: {stdout}");
+    stdout
+}
+
+#[test]
+#[ignore = "needs qemu-system-arm, run with --ignored"]
+fn test_examples_under_qemu() {
+    let elf = build();
+
+    for example in aoc_examples::load() {
+        if !DAYS.contains(&example.day) {
+            continue;
+        }
+
+        let stdout = run(&elf, example.day, &example.path());
+        let name = example.name();
+        let report = stdout
+            .lines()
+            .find_map(Report::parse)
+            .unwrap_or_else(|| panic!("{name} printed no answers: {stdout}"));
+        for (part, solved) in [(1, report.part1), (2, report.part2)] {
+            if let Some(answer) = example.answer(part) {
+                assert_eq!(solved, answer, "{name} part {part}");
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Made up puzzle inputs of any size, for benchmarking and fuzzing.
+//!
+//! Every generator is seeded, so a slow or failing input can be reproduced
+//! from its day, seed and size alone. Unlike the ones in `cdiff`, these
+//! aren't held to the C buffer sizes: the point is to go well past the real
+//! input and see which solvers keep up.
+
+mod machines;
+mod maps;
+mod records;
+
+use aoc_core::rng::Rng;
+
+/// Every day with a generator.
+pub const DAYS: [u8; 24] = [
+    1, 2, 3, 4, 5, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// How big to make it, counted however suits the day: lines for the
+    /// days with a thing per line, cells along a side for the maps. `None`
+    /// is about the size of a real input, see `real_size`.
+    pub size: Option<usize>,
+    /// How many pairs of wires to swap in the day 24 adder.
+    pub swaps: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            size: None,
+            swaps: 4,
+        }
+    }
+}
+
+/// About how big a real input for `day` is, in the units of `Params::size`.
+pub fn real_size(day: u8) -> Option<usize> {
+    let size = match day {
+        1 | 2 => 1000,
+        3 => 6,
+        4 => 140,
+        5 => 200,
+        7 => 850,
+        8 => 50,
+        9 => 19_999,
+        10 => 50,
+        11 => 8,
+        12 => 140,
+        13 => 320,
+        14 => 500,
+        15 => 50,
+        16 => 141,
+        17 => 16,
+        18 => 71,
+        19 => 400,
+        20 => 141,
+        21 => 5,
+        22 => 2000,
+        23 => 520,
+        24 => 45,
+        25 => 500,
+        _ => return None,
+    };
+    Some(size)
+}
+
+/// A random input for `day`, or `None` if there is no generator for it.
+pub fn input(day: u8, params: &Params, rng: &mut Rng) -> Option<String> {
+    let size = params.size.or(real_size(day))?;
+    let input = match day {
+        1 => records::location_lists(rng, size),
+        2 => records::reports(rng, size),
+        3 => records::memory(rng, size),
+        4 => maps::word_search(rng, size),
+        5 => records::print_queue(rng, size),
+        7 => records::equations(rng, size),
+        8 => maps::antenna_map(rng, size),
+        9 => maps::disk_map(rng, size),
+        10 => maps::topographic_map(rng, size),
+        11 => records::stones(rng, size),
+        12 => maps::garden(rng, size),
+        13 => records::claw_machines(rng, size),
+        14 => records::robots(rng, size),
+        15 => maps::warehouse(rng, size),
+        16 => maps::reindeer_maze(rng, size),
+        17 => machines::program(rng, size),
+        18 => maps::falling_bytes(rng, size),
+        19 => records::towels(rng, size),
+        20 => maps::racetrack(rng, size),
+        21 => records::door_codes(rng, size),
+        22 => records::secrets(rng, size),
+        23 => records::network(rng, size),
+        24 => machines::adder(rng, size, params.swaps),
+        25 => records::locks_and_keys(rng, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_input() {
+        let params = Params {
+            size: Some(10),
+            ..Params::default()
+        };
+        for day in DAYS {
+            let first = input(day, &params, &mut Rng::new(1)).unwrap();
+            let again = input(day, &params, &mut Rng::new(1)).unwrap();
+            assert_eq!(first, again, "day {day}");
+            assert!(first.ends_with('\n'), "day {day}");
+        }
+    }
+
+    #[test]
+    fn test_sizes() {
+        for day in DAYS {
+            assert!(real_size(day).is_some(), "day {day}");
+        }
+        assert_eq!(input(6, &Params::default(), &mut Rng::new(0)), None);
+
+        let lines = |size| {
+            let params = Params {
+                size: Some(size),
+                ..Params::default()
+            };
+            input(22, &params, &mut Rng::new(0))
+                .unwrap()
+                .lines()
+                .count()
+        };
+        assert_eq!((lines(3), lines(5000)), (3, 5000));
+    }
+}
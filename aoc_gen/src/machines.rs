@@ -0,0 +1,149 @@
+//! The days that describe a machine: the 3-bit computer and the adder.
+
+use aoc_core::rng::Rng;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Day 17, a program in the same shape as the real ones, which prints
+/// one 3-bit number for each of the `size` octal digits of register A.
+///
+/// Only the shapes that some value of A makes print themselves are used,
+/// so part 2 always has an answer.
+pub fn program(rng: &mut Rng, size: usize) -> String {
+    // register A has to fit in a u64
+    let digits = size.clamp(1, 21) as u32;
+    let low = if digits == 1 { 0 } else { 8u64.pow(digits - 1) };
+    let high = 8u64.checked_pow(digits).map_or(u64::MAX, |high| high - 1);
+    let a = rng.range(low, high);
+
+    // B = A % 8, B ^= x, C = A >> B, B ^= y, B ^= C, A >>= 3, out B, loop
+    // bxc ignores its operand, but 7 is never a valid one
+    let program = loop {
+        let (x, y, z) = (rng.range(0, 7), rng.range(0, 7), rng.range(0, 6));
+        let program = [2, 4, 1, x, 7, 5, 1, y, 4, z, 0, 3, 5, 5, 3, 0];
+        if quine(&program).is_some() {
+            break program;
+        }
+    };
+    let program = program.map(|n| n.to_string()).join(",");
+    format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n")
+}
+
+/// What `program` prints starting from register A.
+fn run(program: &[u64; 16], mut a: u64) -> Vec<u64> {
+    let (x, y) = (program[3], program[7]);
+    let mut output = Vec::new();
+    while a != 0 {
+        let b = (a % 8) ^ x;
+        output.push((b ^ y ^ (a >> b)) % 8);
+        a >>= 3;
+    }
+    output
+}
+
+/// The lowest register A that makes `program` print itself, if any.
+fn quine(program: &[u64; 16]) -> Option<u64> {
+    // each number printed only depends on A from its own 3 bits up, so A
+    // can be built from the top, 3 bits for every number from the end
+    let mut found = vec![0];
+    for printed in 1..=program.len() {
+        let tail = &program[program.len() - printed..];
+        found = found
+            .into_iter()
+            .flat_map(|a| (0..8).map(move |bits| a * 8 + bits))
+            .filter(|&a| a != 0 && run(program, a) == tail)
+            .collect();
+    }
+    found.into_iter().min()
+}
+
+/// A wire name that can't be mistaken for an input or output bit.
+fn wire_name(rng: &mut Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let first = char::from(b'a' + rng.range(0, 22) as u8);
+        let mut name = String::from(first);
+        name.extend((0..2).map(|_| char::from(b'a' + rng.range(0, 25) as u8)));
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Day 24, a ripple carry adder of two `size` bit numbers, with `swaps`
+/// pairs of gate outputs swapped over, at most one pair to a bit.
+///
+/// Each swap stays inside one bit's full adder, and only between outputs
+/// that can trade places without making a loop, so part 1 still settles.
+pub fn adder(rng: &mut Rng, size: usize, swaps: usize) -> String {
+    // the bits are numbered with two digits, and z has one more than x and y
+    let bits = size.clamp(2, 99);
+    let mut taken = HashSet::new();
+    let mut gates = Vec::new();
+
+    gates.push([
+        "x00".to_owned(),
+        "XOR".to_owned(),
+        "y00".to_owned(),
+        "z00".to_owned(),
+    ]);
+    let mut carry = wire_name(rng, &mut taken);
+    gates.push([
+        "x00".to_owned(),
+        "AND".to_owned(),
+        "y00".to_owned(),
+        carry.clone(),
+    ]);
+
+    // where the five gates of every bit after the first start
+    let mut outputs = Vec::new();
+    for bit in 1..bits {
+        let (x, y, z) = (
+            format!("x{bit:02}"),
+            format!("y{bit:02}"),
+            format!("z{bit:02}"),
+        );
+        let sum = wire_name(rng, &mut taken);
+        let both = wire_name(rng, &mut taken);
+        let carried = wire_name(rng, &mut taken);
+        let next_carry = if bit == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            wire_name(rng, &mut taken)
+        };
+
+        let first = gates.len();
+        gates.push([x.clone(), "XOR".to_owned(), y.clone(), sum.clone()]);
+        gates.push([x, "AND".to_owned(), y, both.clone()]);
+        gates.push([sum.clone(), "XOR".to_owned(), carry.clone(), z]);
+        gates.push([sum, "AND".to_owned(), carry, carried.clone()]);
+        gates.push([both, "OR".to_owned(), carried, next_carry.clone()]);
+        outputs.push(first);
+        carry = next_carry;
+    }
+
+    // by gate within a bit, these pairs can swap without any wire feeding
+    // back into itself: sum and both, and the output with carried, carry
+    // out or both
+    const SAFE: [(usize, usize); 4] = [(0, 1), (2, 3), (2, 4), (2, 1)];
+    rng.shuffle(&mut outputs);
+    for &first in outputs.iter().take(swaps) {
+        let (one, other) = *rng.pick(&SAFE);
+        let out = gates[first + one][3].clone();
+        gates[first + one][3] = std::mem::replace(&mut gates[first + other][3], out);
+    }
+
+    let mut input = String::new();
+    for name in ["x", "y"] {
+        for bit in 0..bits {
+            writeln!(input, "{name}{bit:02}: {}", rng.range(0, 1)).unwrap();
+        }
+    }
+    input.push('\n');
+
+    rng.shuffle(&mut gates);
+    for [a, op, b, out] in gates {
+        let (a, b) = if rng.one_in(2) { (a, b) } else { (b, a) };
+        writeln!(input, "{a} {op} {b} -> {out}").unwrap();
+    }
+    input
+}
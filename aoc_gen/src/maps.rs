@@ -0,0 +1,216 @@
+//! The days drawn on a map, all of them square and `size` cells a side.
+
+use aoc_core::rng::Rng;
+use aoc_grid::{Dir, Pos, VecGrid};
+use std::fmt::Write;
+
+fn blank(size: usize, fill: char) -> VecGrid<char> {
+    VecGrid::new(size, size, fill).unwrap()
+}
+
+fn render(map: &VecGrid<char>) -> String {
+    let mut input = String::new();
+    map.render(&mut input, |_, &tile| tile).unwrap();
+    input
+}
+
+fn random_pos(rng: &mut Rng, size: usize) -> Pos {
+    let last = size as u64 - 1;
+    Pos::new(rng.range(0, last) as usize, rng.range(0, last) as usize)
+}
+
+/// Day 4.
+pub fn word_search(rng: &mut Rng, size: usize) -> String {
+    let mut map = blank(size, 'X');
+    for pos in map.positions() {
+        map[pos] = *rng.pick(&['X', 'M', 'A', 'S']);
+    }
+    render(&map)
+}
+
+/// Day 8, with a few antennas for each of a good many frequencies.
+pub fn antenna_map(rng: &mut Rng, size: usize) -> String {
+    let frequencies: Vec<char> = ('a'..='z').chain('A'..='Z').chain('0'..='9').collect();
+    let mut map = blank(size, '.');
+
+    for _ in 0..(size * size / 12).max(2) {
+        let pos = random_pos(rng, size);
+        map[pos] = *rng.pick(&frequencies);
+    }
+    render(&map)
+}
+
+/// Day 9, which is one long line of `size` digits rather than a map.
+pub fn disk_map(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::with_capacity(size + 1);
+
+    // it starts and ends with a file, and no file is empty
+    for idx in 0..(size | 1) {
+        let low = if idx % 2 == 0 { 1 } else { 0 };
+        write!(input, "{}", rng.range(low, 9)).unwrap();
+    }
+    input.push('\n');
+    input
+}
+
+/// Day 10, noise with trails laid over it so there's something to find.
+pub fn topographic_map(rng: &mut Rng, size: usize) -> String {
+    let mut heights = VecGrid::new(size, size, 0u8).unwrap();
+    for pos in heights.positions() {
+        heights[pos] = rng.range(0, 9) as u8;
+    }
+
+    // each trail wanders off from a random spot, climbing as it goes
+    for _ in 0..(size * size / 20).max(1) {
+        let mut pos = random_pos(rng, size);
+        let mut dir = *rng.pick(&Dir::ALL);
+        for height in 0..=9 {
+            heights[pos] = height;
+            // never straight back, but it can still cross itself
+            dir = *rng.pick(&[dir.turn_left(), dir, dir.turn_right()]);
+            let Some(next) = heights.step(pos, dir) else {
+                break;
+            };
+            pos = next;
+        }
+    }
+
+    let mut input = String::new();
+    heights
+        .render(&mut input, |_, &height| char::from(b'0' + height))
+        .unwrap();
+    input
+}
+
+/// Day 12, with plots that mostly copy a neighbour so regions form.
+pub fn garden(rng: &mut Rng, size: usize) -> String {
+    let mut map = blank(size, 'A');
+
+    for pos in map.positions() {
+        let copy = [Dir::North, Dir::West][rng.range(0, 1) as usize];
+        map[pos] = match map.step(pos, copy) {
+            Some(from) if !rng.one_in(6) => map[from],
+            _ => char::from(b'A' + rng.range(0, 25) as u8),
+        };
+    }
+    render(&map)
+}
+
+/// Day 15, walled in, with eight moves for every cell.
+pub fn warehouse(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4);
+    let mut map = blank(size, '.');
+
+    for pos in map.positions() {
+        let edge = pos.row == 0 || pos.col == 0 || pos.row == size - 1 || pos.col == size - 1;
+        map[pos] = match rng.range(0, 9) {
+            _ if edge => '#',
+            0 => '#',
+            1..=4 => 'O',
+            _ => '.',
+        };
+    }
+    let inside = |rng: &mut Rng| rng.range(1, size as u64 - 2) as usize;
+    let robot = Pos::new(inside(rng), inside(rng));
+    map[robot] = '@';
+
+    let mut input = render(&map);
+    input.push('\n');
+    let moves: String = (0..size * size * 8)
+        .map(|_| *rng.pick(&['^', '>', 'v', '<']))
+        .collect();
+    for line in moves.as_bytes().chunks(1000) {
+        input.push_str(std::str::from_utf8(line).unwrap());
+        input.push('\n');
+    }
+    input
+}
+
+/// A maze of `size` by `size` cells, odd so that it can have a wall all round,
+/// with corridors on the odd rows and columns as a random spanning tree.
+/// Returns the maze and, for each corridor cell, where it was reached from.
+fn spanning_tree(rng: &mut Rng, size: usize) -> (VecGrid<char>, VecGrid<Option<Pos>>) {
+    let size = size.max(5) | 1;
+    let mut map = blank(size, '#');
+    let mut parents = VecGrid::new(size, size, None).unwrap();
+
+    let start = Pos::new(size - 2, 1);
+    map[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&pos) = stack.last() {
+        let mut dirs = Dir::ALL;
+        rng.shuffle(&mut dirs);
+        let next = dirs.into_iter().find_map(|dir| {
+            let wall = map.step(pos, dir)?;
+            let next = map.step(wall, dir)?;
+            (map[next] == '#').then_some((wall, next))
+        });
+
+        match next {
+            Some((wall, next)) => {
+                map[wall] = '.';
+                map[next] = '.';
+                parents[wall] = Some(pos);
+                parents[next] = Some(wall);
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    (map, parents)
+}
+
+/// Day 16, a maze with a few loops in it so that there are choices to make.
+pub fn reindeer_maze(rng: &mut Rng, size: usize) -> String {
+    let (mut map, _) = spanning_tree(rng, size);
+    let size = map.width();
+
+    for pos in map.positions() {
+        let inside = pos.row > 0 && pos.col > 0 && pos.row < size - 1 && pos.col < size - 1;
+        if inside && map[pos] == '#' && rng.one_in(10) {
+            map[pos] = '.';
+        }
+    }
+    map[Pos::new(size - 2, 1)] = 'S';
+    map[Pos::new(1, size - 2)] = 'E';
+    render(&map)
+}
+
+/// Day 18, every cell but the two corners falling in a random order.
+pub fn falling_bytes(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut cells: Vec<_> = (0..size)
+        .flat_map(|row| (0..size).map(move |col| (row, col)))
+        .filter(|&cell| cell != (0, 0) && cell != (size - 1, size - 1))
+        .collect();
+    rng.shuffle(&mut cells);
+
+    let mut input = String::new();
+    for (row, col) in cells {
+        writeln!(input, "{row},{col}").unwrap();
+    }
+    input
+}
+
+/// Day 20, a single winding track through an otherwise solid maze.
+pub fn racetrack(rng: &mut Rng, size: usize) -> String {
+    let (tree, parents) = spanning_tree(rng, size);
+    let size = tree.width();
+    let mut map = blank(size, '#');
+
+    // only the way from the end back to the start is kept, so there's
+    // just the one track and nowhere to turn off it
+    let start = Pos::new(size - 2, 1);
+    let end = Pos::new(1, size - 2);
+    let mut pos = end;
+    while let Some(parent) = parents[pos] {
+        map[pos] = '.';
+        pos = parent;
+    }
+    map[start] = 'S';
+    map[end] = 'E';
+    render(&map)
+}
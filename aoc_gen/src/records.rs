@@ -0,0 +1,362 @@
+//! The days with one thing to a line, or to a short block of lines.
+
+use aoc_core::rng::Rng;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Day 1, with `size` pairs of ids.
+pub fn location_lists(rng: &mut Rng, size: usize) -> String {
+    let mut left = Vec::with_capacity(size);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let id = rng.range(10_000, 99_999);
+        left.push(id);
+        // reuse a left hand id now and then or part 2 is always 0
+        let other = if rng.one_in(3) {
+            *rng.pick(&left)
+        } else {
+            rng.range(10_000, 99_999)
+        };
+        writeln!(input, "{id}   {other}").unwrap();
+    }
+    input
+}
+
+/// Day 2, with `size` reports.
+pub fn reports(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let increasing = rng.one_in(2);
+        // eight steps of at most 6 can't take it below zero
+        let mut level = 50i64;
+        let mut levels = vec![level];
+        for _ in 1..rng.range(5, 8) {
+            let step = if rng.one_in(8) {
+                rng.range(0, 6) as i64
+            } else {
+                rng.range(1, 3) as i64
+            };
+            let up = increasing != rng.one_in(10);
+            level += if up { step } else { -step };
+            levels.push(level);
+        }
+
+        let levels: Vec<_> = levels.iter().map(i64::to_string).collect();
+        writeln!(input, "{}", levels.join(" ")).unwrap();
+    }
+    input
+}
+
+/// Day 3, with `size` lines of corrupted memory.
+pub fn memory(rng: &mut Rng, size: usize) -> String {
+    const NOISE: &[&str] = &[
+        "x",
+        "?",
+        "!",
+        "^",
+        "&",
+        "[",
+        "]",
+        "(",
+        ")",
+        ",",
+        " ",
+        "'",
+        "+",
+        "how",
+        "who",
+        "what",
+        "select",
+        "from",
+        "mul",
+        "mul(",
+        "do",
+        "don't",
+        "mul[3,7]",
+        "mul ( 2 , 4 )",
+        "undo()",
+    ];
+    let mut input = String::new();
+
+    for _ in 0..size {
+        for _ in 0..rng.range(400, 800) {
+            let a = rng.range(1, 999);
+            let b = rng.range(1, 999);
+            match rng.range(0, 9) {
+                0..=2 => write!(input, "mul({a},{b})").unwrap(),
+                3 => write!(input, "mul({a},{b}]").unwrap(),
+                4 => input.push_str("do()"),
+                5 => input.push_str("don't()"),
+                _ => {
+                    let noise = *rng.pick(NOISE);
+                    input.push_str(noise);
+                }
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// Day 5, with `size` updates.
+pub fn print_queue(rng: &mut Rng, size: usize) -> String {
+    // the rules give every pair of pages an order, like the real input does
+    let mut pages: Vec<u64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules = Vec::new();
+    for (idx, before) in pages.iter().enumerate() {
+        for after in &pages[idx + 1..] {
+            rules.push((*before, *after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut input = String::new();
+    for (before, after) in rules {
+        writeln!(input, "{before}|{after}").unwrap();
+    }
+    input.push('\n');
+
+    for _ in 0..size {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(2 * rng.range(2, 11) as usize + 1);
+        // about half should already be in order for part 1 to count
+        if rng.one_in(2) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+
+        let update: Vec<_> = update.iter().map(u64::to_string).collect();
+        writeln!(input, "{}", update.join(",")).unwrap();
+    }
+    input
+}
+
+/// Day 7, with `size` equations.
+pub fn equations(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let terms: Vec<u64> = (0..rng.range(2, 12)).map(|_| rng.range(1, 999)).collect();
+        // make most of them solvable, with concatenation or without, and
+        // fall back to adding whenever the target would get too big
+        let mut target = terms[0];
+        for &term in &terms[1..] {
+            let joined = 10u64
+                .checked_pow(term.ilog10() + 1)
+                .and_then(|shift| target.checked_mul(shift))
+                .and_then(|shifted| shifted.checked_add(term));
+            target = match rng.range(0, 2) {
+                0 => None,
+                1 => target.checked_mul(term),
+                _ => joined,
+            }
+            .filter(|&target| target < 1 << 48)
+            .unwrap_or(target + term);
+        }
+        if rng.one_in(3) {
+            target += rng.range(1, 9);
+        }
+
+        let terms: Vec<_> = terms.iter().map(u64::to_string).collect();
+        writeln!(input, "{target}: {}", terms.join(" ")).unwrap();
+    }
+    input
+}
+
+/// Day 11, with `size` stones.
+pub fn stones(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<_> = (0..size)
+        .map(|_| rng.range(0, 999_999).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+/// Day 13, with `size` claw machines.
+pub fn claw_machines(rng: &mut Rng, size: usize) -> String {
+    let mut machines = Vec::with_capacity(size);
+
+    for _ in 0..size {
+        let a = (rng.range(10, 99), rng.range(10, 99));
+        let b = (rng.range(10, 99), rng.range(10, 99));
+        // most can be won, and the rest are out of reach
+        let prize = if rng.one_in(3) {
+            (rng.range(1000, 20_000), rng.range(1000, 20_000))
+        } else {
+            let (a_presses, b_presses) = (rng.range(10, 100), rng.range(10, 100));
+            (
+                a.0 * a_presses + b.0 * b_presses,
+                a.1 * a_presses + b.1 * b_presses,
+            )
+        };
+
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    machines.join("\n")
+}
+
+/// Day 14, with `size` robots on the fixed 101 by 103 floor.
+///
+/// Seven in ten of them are planted so that at some second within the
+/// floor's cycle they stand packed into a tree, which is what part 2 looks
+/// for. The rest wander about at random.
+pub fn robots(rng: &mut Rng, size: usize) -> String {
+    const WIDTH: i64 = 101;
+    const HEIGHT: i64 = 103;
+
+    // rows of 1, 3, 5 and so on, so n rows hold n * n robots, and no more
+    // rows than fit across the floor
+    let tree = (size * 7 / 10).min(2500);
+    let rows = (0..).find(|rows| rows * rows >= tree).unwrap() as i64;
+    let top = rng.range(0, (HEIGHT - rows) as u64) as i64;
+    let middle = rng.range(rows as u64, (WIDTH - rows) as u64) as i64;
+    let mut planted = (0..rows)
+        .flat_map(|row| (-row..=row).map(move |col| (middle + col, top + row)))
+        .take(tree);
+
+    let when = rng.range(1, (WIDTH * HEIGHT - 1) as u64) as i64;
+    let mut input = String::new();
+    for _ in 0..size {
+        let (x, y) = planted.next().unwrap_or_else(|| {
+            (
+                rng.range(0, WIDTH as u64 - 1) as i64,
+                rng.range(0, HEIGHT as u64 - 1) as i64,
+            )
+        });
+        let speed = |rng: &mut Rng| rng.range(0, 198) as i64 - 99;
+        let (dx, dy) = (speed(rng), speed(rng));
+        // run back from where it is at `when` to where it starts
+        let (x, y) = (
+            (x - dx * when).rem_euclid(WIDTH),
+            (y - dy * when).rem_euclid(HEIGHT),
+        );
+        writeln!(input, "p={x},{y} v={dx},{dy}").unwrap();
+    }
+    input
+}
+
+/// Day 19, with `size` designs.
+pub fn towels(rng: &mut Rng, size: usize) -> String {
+    const STRIPES: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+    let mut seen = HashSet::new();
+    let mut towels = Vec::new();
+    while towels.len() < 447 {
+        let len = rng.range(1, 8);
+        let towel: String = (0..len).map(|_| *rng.pick(&STRIPES)).collect();
+        // no towel ends in black, so no design that does can be made
+        if !towel.ends_with('b') && seen.insert(towel.clone()) {
+            towels.push(towel);
+        }
+    }
+
+    let mut input = towels.join(", ");
+    input.push_str("\n\n");
+    for _ in 0..size {
+        let len = rng.range(20, 60) as usize;
+        let mut design = String::new();
+        // most are laid out from towels, the rest can't be
+        if rng.one_in(4) {
+            design.extend((1..len).map(|_| *rng.pick(&STRIPES)));
+            design.push('b');
+        } else {
+            while design.len() < len {
+                design.push_str(rng.pick(&towels).as_str());
+            }
+        }
+        writeln!(input, "{design}").unwrap();
+    }
+    input
+}
+
+/// Day 21, with `size` door codes.
+pub fn door_codes(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        writeln!(input, "{:03}A", rng.range(0, 999)).unwrap();
+    }
+    input
+}
+
+/// Day 22, with `size` buyers' secrets.
+pub fn secrets(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        writeln!(input, "{}", rng.range(1, 16_777_215)).unwrap();
+    }
+    input
+}
+
+/// Day 23, with `size` computers, at most one for every pair of letters.
+pub fn network(rng: &mut Rng, size: usize) -> String {
+    let mut names: Vec<String> = ('a'..='z')
+        .flat_map(|first| ('a'..='z').map(move |second| format!("{first}{second}")))
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.max(2));
+
+    let mut links = HashSet::new();
+    let mut link = |a: usize, b: usize| {
+        if a != b {
+            links.insert((a.min(b), a.max(b)));
+        }
+    };
+
+    // a dozen or so links each, and one big party for part 2
+    let party: Vec<_> = (0..names.len().min(13)).collect();
+    for &a in &party {
+        for &b in &party {
+            link(a, b);
+        }
+    }
+    let last = names.len() as u64 - 1;
+    for a in 0..names.len() {
+        for _ in 0..6 {
+            link(a, rng.range(0, last) as usize);
+        }
+    }
+
+    let mut links: Vec<_> = links.into_iter().collect();
+    links.sort_unstable();
+    rng.shuffle(&mut links);
+
+    let mut input = String::new();
+    for (a, b) in links {
+        let (a, b) = if rng.one_in(2) { (a, b) } else { (b, a) };
+        writeln!(input, "{}-{}", names[a], names[b]).unwrap();
+    }
+    input
+}
+
+/// Day 25, with `size` locks and keys between them.
+pub fn locks_and_keys(rng: &mut Rng, size: usize) -> String {
+    let mut schematics = Vec::with_capacity(size);
+
+    for _ in 0..size {
+        let heights: Vec<u64> = (0..5).map(|_| rng.range(0, 5)).collect();
+        let lock = rng.one_in(2);
+        let mut schematic = String::new();
+        for row in 0..7 {
+            for &height in &heights {
+                let filled = if lock {
+                    row <= height
+                } else {
+                    row >= 6 - height
+                };
+                schematic.push(if filled { '#' } else { '.' });
+            }
+            schematic.push('\n');
+        }
+        schematics.push(schematic);
+    }
+    schematics.join("\n")
+}
@@ -0,0 +1,446 @@
+//! Rectangular maps of cells, which is what most of the puzzles are drawn on.
+//!
+//! A `Grid` keeps its cells row by row in one `Storage` list, so the same
+//! map code runs on a fixed buffer on the Pico and on the heap on the host.
+//! Positions are `Pos`es, and stepping off any edge gives `None` rather than
+//! wrapping or panicking. A `GridView` is the read-only kind that is
+//! looked up where it sits in the input instead of being copied.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod view;
+
+pub use view::GridView;
+
+use aoc_core::error::{CapacityError, InputError, OrFull};
+use aoc_core::parse::{self, Lines};
+use aoc_core::storage::{List, Storage};
+use core::fmt;
+use core::ops::{Index, IndexMut};
+
+#[cfg(feature = "alloc")]
+use aoc_core::storage::Alloc;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Moves by `offset`, or `None` past the top or left edge. `Grid::step`
+    /// also stops at the bottom and right.
+    pub fn offset(self, offset: impl Into<Offset>) -> Option<Self> {
+        let offset = offset.into();
+        Some(Self {
+            row: self.row.checked_add_signed(offset.row)?,
+            col: self.col.checked_add_signed(offset.col)?,
+        })
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// One of the four ways to step on a grid, with north at the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    /// Clockwise from north.
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir::North => Dir::East,
+            Dir::East => Dir::South,
+            Dir::South => Dir::West,
+            Dir::West => Dir::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+/// A move of any size in any direction, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Offset {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Offset {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// The eight single steps, clockwise from north.
+    pub const ALL8: [Offset; 8] = [
+        Offset::new(-1, 0),
+        Offset::new(-1, 1),
+        Offset::new(0, 1),
+        Offset::new(1, 1),
+        Offset::new(1, 0),
+        Offset::new(1, -1),
+        Offset::new(0, -1),
+        Offset::new(-1, -1),
+    ];
+}
+
+impl From<Dir> for Offset {
+    fn from(dir: Dir) -> Self {
+        match dir {
+            Dir::North => Offset::new(-1, 0),
+            Dir::East => Offset::new(0, 1),
+            Dir::South => Offset::new(1, 0),
+            Dir::West => Offset::new(0, -1),
+        }
+    }
+}
+
+/// `N` cells at most, with `width` of them to a row.
+pub struct Grid<T, S: Storage, const N: usize> {
+    cells: S::Vec<T, N>,
+    width: usize,
+    height: usize,
+}
+
+/// A grid on the heap, with no limit on its size.
+#[cfg(feature = "alloc")]
+pub type VecGrid<T> = Grid<T, Alloc, 0>;
+
+impl<T, S: Storage, const N: usize> Grid<T, S, N> {
+    /// A grid with every cell set to `fill`. The error's line is the first
+    /// row that didn't fit.
+    pub fn new(width: usize, height: usize, fill: T) -> Result<Self, CapacityError>
+    where
+        T: Clone,
+    {
+        let mut cells = S::Vec::<T, N>::default();
+        for row in 0..height {
+            for _ in 0..width {
+                cells.push(fill.clone()).or_full("grid", N, row + 1)?;
+            }
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Reads a whole input that is nothing but the map, turning each
+    /// character into a cell with `cell`. Characters it gives `None` for are
+    /// errors, described by `expected`.
+    pub fn parse(
+        input: &str,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &'static str,
+    ) -> Result<Self, InputError> {
+        let mut lines = Lines::new(input);
+        let grid = Self::read(&mut lines, cell, expected)?;
+
+        // the map stopped at a blank line
+        if let Some(line) = lines.next() {
+            return Err(line.error(parse::Expected::Thing(expected)).into());
+        }
+        Ok(grid)
+    }
+
+    /// Like `parse`, for a map with more input after it. Reads up to the
+    /// first blank line, which is left in `lines`.
+    pub fn read(
+        lines: &mut Lines,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &'static str,
+    ) -> Result<Self, InputError> {
+        let mut cells = S::Vec::<T, N>::default();
+        let valid = |chr| cell(chr).is_some();
+        let (width, height) =
+            parse::grid_lines::<InputError>(lines, valid, expected, |row, _, chr| {
+                // only characters `valid` likes get this far
+                if let Some(value) = cell(chr) {
+                    cells.push(value).or_full("grid", N, row + 1)?;
+                }
+                Ok(())
+            })?;
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.width + pos.col])
+    }
+
+    /// Moves `pos` by `offset`, or `None` if that leaves the grid.
+    pub fn step(&self, pos: Pos, offset: impl Into<Offset>) -> Option<Pos> {
+        pos.offset(offset).filter(|&next| self.contains(next))
+    }
+
+    /// The up to four cells next to `pos`, in `Dir::ALL` order.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The up to eight cells around `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Offset::ALL8
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T, S, N> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first cell, row by row, that `pred` likes.
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| pred(cell).then_some(pos))
+    }
+
+    /// Panics if there is no such row, like slicing does.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cells = if col < self.width {
+            &self.cells[col..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// The cells from `from` on, repeatedly moving by `offset` until falling
+    /// off the grid. A diagonal `offset` gives the diagonals.
+    pub fn ray(&self, from: Pos, offset: impl Into<Offset>) -> impl Iterator<Item = (Pos, &T)> {
+        let offset = offset.into();
+        let start = Some(from).filter(|&pos| self.contains(pos));
+        core::iter::successors(start, move |&pos| self.step(pos, offset))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Draws the grid a row to a line, with `cell` picking each character.
+    pub fn render(&self, out: &mut impl fmt::Write, cell: impl Fn(Pos, &T) -> char) -> fmt::Result {
+        for (pos, value) in self.iter() {
+            out.write_char(cell(pos, value))?;
+            if pos.col + 1 == self.width {
+                out.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, S: Storage, const N: usize> Index<Pos> for Grid<T, S, N> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(value) => value,
+            None => panic!("{pos} is outside a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T, S: Storage, const N: usize> IndexMut<Pos> for Grid<T, S, N> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(value) => value,
+            None => panic!("{pos} is outside a {width}x{height} grid"),
+        }
+    }
+}
+
+impl<T, S: Storage, const N: usize> Clone for Grid<T, S, N>
+where
+    S::Vec<T, N>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cells: self.cells.clone(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: fmt::Debug, S: Storage, const N: usize> fmt::Debug for Grid<T, S, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("cells", &&self.cells[..])
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use aoc_core::parse::{Found, ParseError};
+    use aoc_core::storage::Heapless;
+    use std::string::String;
+    use std::vec::Vec;
+
+    const MAZE: &str = "#####
+#S..#
+#.#E#
+#####
+";
+
+    fn maze() -> VecGrid<char> {
+        VecGrid::parse(MAZE, |chr| "#.SE".contains(chr).then_some(chr), "a tile").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = maze();
+        assert_eq!((grid.width(), grid.height()), (5, 4));
+        assert_eq!(grid.find(|&tile| tile == 'E'), Some(Pos::new(2, 3)));
+        assert_eq!(grid.row(1), ['#', 'S', '.', '.', '#']);
+        assert_eq!(grid.column(2).collect::<String>(), "#.##");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = VecGrid::parse("#.\n.x\n", |chr| (chr != 'x').then_some(chr), "a tile");
+        assert_eq!(
+            err.unwrap_err(),
+            InputError::Parse(ParseError {
+                line: 2,
+                column: 2,
+                expected: parse::Expected::Thing("a tile"),
+                found: Found::Char('x'),
+            })
+        );
+    }
+
+    #[test]
+    fn test_too_big() {
+        let err = Grid::<char, Heapless, 8>::parse(MAZE, Some, "a tile").unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Capacity(CapacityError {
+                buffer: "grid",
+                capacity: 8,
+                line: 2,
+            })
+        );
+        assert!(Grid::<u8, Heapless, 8>::new(3, 3, 0).is_err());
+    }
+
+    #[test]
+    fn test_stepping() {
+        let grid = maze();
+        assert_eq!(grid.step(Pos::new(0, 0), Dir::North), None);
+        assert_eq!(grid.step(Pos::new(3, 4), Dir::East), None);
+        assert_eq!(grid.step(Pos::new(1, 1), Dir::South), Some(Pos::new(2, 1)));
+
+        let corner: Vec<_> = grid.neighbours(Pos::new(0, 0)).collect();
+        assert_eq!(corner, [Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbours(Pos::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Pos::new(3, 4)).count(), 3);
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir::North.turn_right(), Dir::East);
+        assert_eq!(Dir::North.turn_left(), Dir::West);
+        assert_eq!(Dir::East.reverse(), Dir::West);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = maze();
+        let diagonal: String = grid
+            .ray(Pos::new(0, 0), Offset::new(1, 1))
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(diagonal, "#S##");
+        let back: String = grid
+            .ray(Pos::new(2, 3), Dir::West)
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(back, "E#.#");
+        assert_eq!(grid.ray(Pos::new(9, 9), Dir::West).count(), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = maze();
+        grid[Pos::new(1, 2)] = 'O';
+        let mut text = String::new();
+        grid.render(&mut text, |_, &c| c).unwrap();
+        assert_eq!(text, MAZE.replacen("S.", "SO", 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 5) is outside a 5x4 grid")]
+    fn test_index_outside() {
+        // without the check this would be the first cell of the next row
+        let _ = maze()[Pos::new(0, 5)];
+    }
+}
@@ -0,0 +1,153 @@
+//! A map read in place, for inputs too big to copy into RAM.
+
+use crate::{Dir, Offset, Pos};
+use aoc_core::parse::ParseError;
+use aoc_core::scan;
+use core::ops::Index;
+
+/// The cells of a map of single byte cells, left where they are in the
+/// input. On the firmware that is flash, so the map costs no RAM at all.
+/// The host hands over an owned copy instead.
+#[derive(Debug, Clone)]
+pub struct GridView<B> {
+    bytes: B,
+    width: usize,
+    height: usize,
+}
+
+impl<B: AsRef<[u8]>> GridView<B> {
+    /// Checks `input` is nothing but the map, with every byte one that
+    /// `valid` likes, which is what lets the cells be looked up by where
+    /// they sit in it. Bytes it doesn't like are errors, described by
+    /// `expected`.
+    pub fn parse(
+        input: B,
+        valid: impl Fn(u8) -> bool,
+        expected: &'static str,
+    ) -> Result<Self, ParseError> {
+        let (width, height) = scan::grid(input.as_ref(), valid, expected)?;
+        Ok(Self {
+            bytes: input,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<u8> {
+        // each row is followed by its newline
+        self.contains(pos)
+            .then(|| self.bytes.as_ref()[pos.row * (self.width + 1) + pos.col])
+    }
+
+    /// Moves `pos` by `offset`, or `None` if that leaves the grid.
+    pub fn step(&self, pos: Pos, offset: impl Into<Offset>) -> Option<Pos> {
+        pos.offset(offset).filter(|&next| self.contains(next))
+    }
+
+    /// The up to four cells next to `pos`, in `Dir::ALL` order.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + Clone + use<B> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, u8)> + Clone + '_ {
+        self.positions().map(|pos| (pos, self[pos]))
+    }
+
+    /// Panics if there is no such row, like slicing does.
+    pub fn row(&self, row: usize) -> &[u8] {
+        let start = row * (self.width + 1);
+        &self.bytes.as_ref()[start..start + self.width]
+    }
+
+    /// The cells from `from` on, repeatedly moving by `offset` until falling
+    /// off the grid.
+    pub fn ray(
+        &self,
+        from: Pos,
+        offset: impl Into<Offset>,
+    ) -> impl Iterator<Item = (Pos, u8)> + '_ {
+        let offset = offset.into();
+        let start = Some(from).filter(|&pos| self.contains(pos));
+        core::iter::successors(start, move |&pos| self.step(pos, offset))
+            .map(|pos| (pos, self[pos]))
+    }
+}
+
+impl<B: AsRef<[u8]>> Index<Pos> for GridView<B> {
+    type Output = u8;
+
+    fn index(&self, pos: Pos) -> &u8 {
+        if !self.contains(pos) {
+            panic!("{pos} is outside a {}x{} grid", self.width, self.height);
+        }
+        &self.bytes.as_ref()[pos.row * (self.width + 1) + pos.col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    const MAZE: &[u8] = b"#####\n#S..#\n#.#E#\n#####";
+
+    fn maze() -> GridView<&'static [u8]> {
+        GridView::parse(MAZE, |byte| b"#.SE".contains(&byte), "a tile").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = maze();
+        assert_eq!((grid.width(), grid.height()), (5, 4));
+        assert_eq!(grid.row(1), b"#S..#");
+        assert_eq!(grid.get(Pos::new(2, 3)), Some(b'E'));
+        assert_eq!(grid.get(Pos::new(1, 5)), None);
+        assert_eq!(grid.iter().filter(|&(_, tile)| tile == b'.').count(), 3);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = GridView::parse(&b"#.\n.x\n"[..], |byte| byte != b'x', "a tile").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_stepping() {
+        let grid = maze();
+        assert_eq!(grid.step(Pos::new(3, 4), Dir::East), None);
+        assert_eq!(grid.neighbours(Pos::new(0, 0)).count(), 2);
+        let diagonal: std::vec::Vec<u8> = grid
+            .ray(Pos::new(0, 0), Offset::new(1, 1))
+            .map(|(_, tile)| tile)
+            .collect();
+        assert_eq!(diagonal, b"#S##");
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 5) is outside a 5x4 grid")]
+    fn test_index_outside() {
+        // without the check this would be a newline
+        let _ = maze()[Pos::new(0, 5)];
+    }
+}
@@ -0,0 +1,365 @@
+//! Shortest path searches over anything that can list its next moves.
+//!
+//! A puzzle describes itself as a `SearchProblem`, and then picks whichever
+//! search suits it: `bfs` when every move costs the same, `dijkstra` or
+//! `astar` when they don't, and `all_shortest_paths` when it needs every
+//! cheapest route rather than just one. These are host only, since they keep
+//! their bookkeeping in `HashMap`s.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+
+    fn start(&self) -> Self::State;
+
+    /// The states one move away from `state`, each with what the move costs.
+    fn successors(&self, state: &Self::State) -> impl IntoIterator<Item = (Self::State, usize)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A guess at the cost still to go for `astar`. It must never be more
+    /// than the real cost, or the path found might not be the cheapest.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+/// One cheapest route, from the start to a goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    pub states: Vec<S>,
+}
+
+/// Every cheapest route to the goals, kept as the cheapest way into each
+/// state so that routes sharing a prefix aren't stored twice.
+#[derive(Debug, Clone)]
+pub struct Dag<S> {
+    pub cost: usize,
+    goals: Vec<S>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Dag<S> {
+    /// The goals reached at the cheapest cost.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Every state on at least one cheapest route.
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut todo = self.goals.clone();
+
+        while let Some(state) = todo.pop() {
+            for prev in self.predecessors.get(&state).into_iter().flatten() {
+                if seen.insert(prev.clone()) {
+                    todo.push(prev.clone());
+                }
+            }
+        }
+
+        seen
+    }
+
+    /// How many different cheapest routes there are, over all the goals.
+    pub fn count_paths(&self) -> usize {
+        let mut counts = HashMap::new();
+        self.goals
+            .iter()
+            .map(|goal| self.count_into(goal, &mut counts))
+            .sum()
+    }
+
+    fn count_into(&self, state: &S, counts: &mut HashMap<S, usize>) -> usize {
+        if let Some(&count) = counts.get(state) {
+            return count;
+        }
+
+        // only the start has nothing leading into it
+        let count = match self.predecessors.get(state) {
+            Some(prevs) => prevs.iter().map(|prev| self.count_into(prev, counts)).sum(),
+            None => 1,
+        };
+        counts.insert(state.clone(), count);
+        count
+    }
+}
+
+/// The nearest goal counting moves, ignoring what each one costs.
+pub fn bfs<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    let start = problem.start();
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if problem.is_goal(&state) {
+            let states = walk_back(&parents, state);
+            return Some(Path {
+                cost: states.len() - 1,
+                states,
+            });
+        }
+
+        for (next, _) in problem.successors(&state) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(state.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// How many moves it takes to reach every state reachable from the start.
+/// Goals are ignored, so this always explores everything.
+pub fn distances<P: SearchProblem>(problem: &P) -> HashMap<P::State, usize> {
+    let start = problem.start();
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state] + 1;
+        for (next, _) in problem.successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// The cheapest goal, trying the cheapest states first.
+pub fn dijkstra<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    best_first(problem, |_| 0)
+}
+
+/// The cheapest goal, trying first the states the heuristic rates closest.
+pub fn astar<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    best_first(problem, |state| problem.heuristic(state))
+}
+
+fn best_first<P: SearchProblem>(
+    problem: &P,
+    heuristic: impl Fn(&P::State) -> usize,
+) -> Option<Path<P::State>> {
+    let start = problem.start();
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    // the heap holds indexes into this, so states don't need to be Ord
+    let mut states = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        let state = states[idx].clone();
+        if costs[&state] < cost {
+            // a cheaper way here was found after this was queued
+            continue;
+        }
+
+        if problem.is_goal(&state) {
+            let states = walk_back(&parents, state);
+            return Some(Path { cost, states });
+        }
+
+        for (next, step) in problem.successors(&state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), Some(state.clone()));
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Every cheapest route to the goals, which all end up at the same cost.
+pub fn all_shortest_paths<P: SearchProblem>(problem: &P) -> Option<Dag<P::State>> {
+    let start = problem.start();
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<_, Vec<_>> = HashMap::new();
+    let mut states = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((0, 0))]);
+    let mut goals = Vec::new();
+    let mut goal_cost = None;
+
+    while let Some(Reverse((cost, idx))) = heap.pop() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+
+        let state = states[idx].clone();
+        if costs[&state] < cost {
+            continue;
+        }
+
+        if problem.is_goal(&state) {
+            goal_cost = Some(cost);
+            goals.push(state);
+            continue;
+        }
+
+        for (next, step) in problem.successors(&state) {
+            let next_cost = cost + step;
+            match costs.get(&next) {
+                Some(&known) if next_cost > known => {}
+                Some(&known) if next_cost == known => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    heap.push(Reverse((next_cost, states.len())));
+                    states.push(next);
+                }
+            }
+        }
+    }
+
+    Some(Dag {
+        cost: goal_cost?,
+        goals,
+        predecessors,
+    })
+}
+
+fn walk_back<S: Clone + Eq + Hash>(parents: &HashMap<S, Option<S>>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(Some(parent)) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+    states.reverse();
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A number line where stepping costs 1 and jumping two costs 3, so
+    /// stepping is always at least as cheap.
+    struct Line {
+        goal: i32,
+        blocked: i32,
+    }
+
+    impl SearchProblem for Line {
+        type State = i32;
+
+        fn start(&self) -> i32 {
+            0
+        }
+
+        fn successors(&self, &state: &i32) -> impl IntoIterator<Item = (i32, usize)> {
+            [(state - 1, 1), (state + 1, 1), (state + 2, 3)]
+                .into_iter()
+                .filter(|&(next, _)| next != self.blocked && (-10..=10).contains(&next))
+        }
+
+        fn is_goal(&self, &state: &i32) -> bool {
+            state == self.goal
+        }
+
+        fn heuristic(&self, &state: &i32) -> usize {
+            state.abs_diff(self.goal) as usize
+        }
+    }
+
+    #[test]
+    fn test_bfs_counts_moves() {
+        let path = bfs(&Line {
+            goal: 4,
+            blocked: 99,
+        })
+        .unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.states, [0, 2, 4]);
+    }
+
+    #[test]
+    fn test_dijkstra_counts_costs() {
+        let line = Line {
+            goal: 4,
+            blocked: 99,
+        };
+        for path in [dijkstra(&line), astar(&line)] {
+            let path = path.unwrap();
+            assert_eq!(path.cost, 4);
+            assert_eq!(path.states.first(), Some(&0));
+            assert_eq!(path.states.last(), Some(&4));
+        }
+    }
+
+    #[test]
+    fn test_blocked() {
+        let line = Line {
+            goal: 4,
+            blocked: 1,
+        };
+        assert_eq!(dijkstra(&line).unwrap().states, [0, 2, 3, 4]);
+
+        let line = Line {
+            goal: 20,
+            blocked: 99,
+        };
+        assert_eq!(bfs(&line), None);
+        assert_eq!(astar(&line), None);
+        assert!(all_shortest_paths(&line).is_none());
+        assert_eq!(distances(&line).len(), 21);
+    }
+
+    /// Walking right or down across a 3x3 square, so every route to the
+    /// far corner is four moves long.
+    struct Square;
+
+    impl SearchProblem for Square {
+        type State = (u8, u8);
+
+        fn start(&self) -> (u8, u8) {
+            (0, 0)
+        }
+
+        fn successors(&self, &(x, y): &(u8, u8)) -> impl IntoIterator<Item = ((u8, u8), usize)> {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x < 3 && y < 3)
+                .map(|next| (next, 1))
+        }
+
+        fn is_goal(&self, &state: &(u8, u8)) -> bool {
+            state == (2, 2)
+        }
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let dag = all_shortest_paths(&Square).unwrap();
+        assert_eq!(dag.cost, 4);
+        assert_eq!(dag.goals(), [(2, 2)]);
+        assert_eq!(dag.count_paths(), 6);
+        assert_eq!(dag.states().len(), 9);
+
+        // with a single goal off to the side there's only one way round
+        let dag = all_shortest_paths(&Line {
+            goal: -3,
+            blocked: 99,
+        })
+        .unwrap();
+        assert_eq!((dag.cost, dag.count_paths()), (3, 1));
+        assert_eq!(dag.states(), HashSet::from([0, -1, -2, -3]));
+    }
+}
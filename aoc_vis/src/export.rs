@@ -0,0 +1,146 @@
+//! Writing frames to files, one per frame, to flick through or stitch into
+//! a video with something like ffmpeg.
+
+use crate::Frame;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// What the terminal would show, caption and all.
+    Text,
+    /// Binary PPM, which about anything can read and is trivial to write.
+    Ppm,
+    Png,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Text => "txt",
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, String> {
+        match name {
+            "text" | "txt" => Ok(Format::Text),
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            _ => Err(format!("{name} isn't text, ppm or png")),
+        }
+    }
+}
+
+/// Colours for whatever isn't blank or a wall, picked by character so the
+/// same thing is the same colour in every frame.
+const PALETTE: [[u8; 3]; 6] = [
+    [230, 80, 70],
+    [250, 190, 40],
+    [90, 200, 90],
+    [70, 160, 240],
+    [190, 110, 230],
+    [240, 240, 240],
+];
+
+fn colour(chr: char) -> [u8; 3] {
+    match chr {
+        ' ' | '.' => [20, 20, 30],
+        '#' => [120, 120, 130],
+        _ => PALETTE[chr as usize % PALETTE.len()],
+    }
+}
+
+/// The frame as RGB bytes, each cell a `scale` pixel square.
+fn pixels(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height) = (frame.cells.width(), frame.cells.height());
+    let mut pixels = Vec::with_capacity(width * height * scale * scale * 3);
+
+    for row in 0..height {
+        let colours: Vec<_> = frame
+            .cells
+            .row(row)
+            .iter()
+            .map(|&chr| colour(chr))
+            .collect();
+        for _ in 0..scale {
+            for rgb in &colours {
+                for _ in 0..scale {
+                    pixels.extend_from_slice(rgb);
+                }
+            }
+        }
+    }
+
+    pixels
+}
+
+fn write_ppm(out: &mut impl Write, frame: &Frame, scale: usize) -> io::Result<()> {
+    let (width, height) = (frame.cells.width() * scale, frame.cells.height() * scale);
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    out.write_all(&pixels(frame, scale))
+}
+
+fn write_png(out: impl Write, frame: &Frame, scale: usize) -> io::Result<()> {
+    let (width, height) = (frame.cells.width() * scale, frame.cells.height() * scale);
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels(frame, scale))?;
+    Ok(writer.finish()?)
+}
+
+/// Writes each frame to `dir` as `frame00000.txt` and on, with each cell
+/// `scale` pixels a side in the images. Returns how many were written.
+pub fn export(
+    frames: impl IntoIterator<Item = Frame>,
+    dir: &Path,
+    format: Format,
+    scale: usize,
+) -> io::Result<usize> {
+    fs::create_dir_all(dir)?;
+
+    let mut written = 0;
+    for frame in frames {
+        let path = dir.join(format!("frame{written:05}.{}", format.extension()));
+        let mut out = BufWriter::new(File::create(path)?);
+        match format {
+            Format::Text => write!(out, "{frame}")?,
+            Format::Ppm => write_ppm(&mut out, &frame, scale)?,
+            Format::Png => write_png(&mut out, &frame, scale)?,
+        }
+        out.flush()?;
+        written += 1;
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::Pos;
+
+    #[test]
+    fn test_ppm() {
+        let mut frame = Frame::new(2, 1, ' ');
+        frame[Pos::new(0, 1)] = '#';
+
+        let mut out = Vec::new();
+        write_ppm(&mut out, &frame, 2).unwrap();
+
+        let blank = [20, 20, 30];
+        let wall = [120, 120, 130];
+        let row = [blank, blank, wall, wall].concat();
+        assert_eq!(out, [b"P6\n4 2\n255\n".as_slice(), &row, &row].concat());
+    }
+}
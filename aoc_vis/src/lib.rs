@@ -0,0 +1,90 @@
+//! Watching the simulation days run.
+//!
+//! A day draws each step of its simulation as a `Frame` of characters and
+//! hands back an iterator of them. `play` shows that in the terminal, with
+//! keys to pause, step and fast-forward, and `export` writes each frame out
+//! as text or an image instead.
+
+mod export;
+mod play;
+
+pub use export::{Format, export};
+pub use play::play;
+
+use aoc_core::storage::Storage;
+use aoc_grid::{Grid, Pos, VecGrid};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// One step of a simulation, a character to a cell.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub cells: VecGrid<char>,
+    /// A line shown above the cells, like which step this is.
+    pub caption: String,
+}
+
+impl Frame {
+    /// A frame blank but for `fill`.
+    pub fn new(width: usize, height: usize, fill: char) -> Self {
+        Self {
+            cells: VecGrid::new(width, height, fill).unwrap(),
+            caption: String::new(),
+        }
+    }
+
+    /// Draws `grid` with `cell` picking each character.
+    pub fn grid<T, S: Storage, const N: usize>(
+        grid: &Grid<T, S, N>,
+        cell: impl Fn(Pos, &T) -> char,
+    ) -> Self {
+        let mut frame = Self::new(grid.width(), grid.height(), ' ');
+        for (pos, value) in grid.iter() {
+            frame[pos] = cell(pos, value);
+        }
+        frame
+    }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+}
+
+impl Index<Pos> for Frame {
+    type Output = char;
+
+    fn index(&self, pos: Pos) -> &char {
+        &self.cells[pos]
+    }
+}
+
+impl IndexMut<Pos> for Frame {
+    fn index_mut(&mut self, pos: Pos) -> &mut char {
+        &mut self.cells[pos]
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.caption.is_empty() {
+            writeln!(f, "{}", self.caption)?;
+        }
+        self.cells.render(f, |_, &chr| chr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        let grid = VecGrid::parse("#..\n.#.", |chr| Some(chr == '#'), "").unwrap();
+        let mut frame = Frame::grid(&grid, |_, &wall| if wall { '#' } else { ' ' });
+        frame[Pos::new(1, 2)] = '@';
+
+        assert_eq!(frame.to_string(), "#  \n #@\n");
+        assert_eq!(frame.caption("step 1").to_string(), "step 1\n#  \n #@\n");
+    }
+}
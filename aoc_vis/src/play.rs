@@ -0,0 +1,109 @@
+//! Playing frames back in the terminal.
+
+use crate::Frame;
+use nix::sys::termios::{
+    LocalFlags, SetArg, SpecialCharacterIndices, Termios, tcgetattr, tcsetattr,
+};
+use std::io::{self, IsTerminal, Read, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Frames gone through for each one drawn while fast-forwarding.
+const FAST_FORWARD: usize = 10;
+
+const KEYS: &str = "space pause, n step, f fast-forward, q quit";
+
+/// Puts the terminal back how it was, however playback ends.
+struct RawKeys {
+    saved: Termios,
+}
+
+impl RawKeys {
+    /// Has keys come through as they're pressed, without echoing them or
+    /// waiting for enter. There are no keys to read unless stdin is a terminal.
+    fn new() -> io::Result<Option<Self>> {
+        let stdin = io::stdin();
+        if !stdin.is_terminal() {
+            return Ok(None);
+        }
+
+        let saved = tcgetattr(&stdin)?;
+        let mut termios = saved.clone();
+        termios
+            .local_flags
+            .remove(LocalFlags::ICANON | LocalFlags::ECHO);
+        // reads return straight away, with nothing if no key is waiting
+        termios.control_chars[SpecialCharacterIndices::VMIN as usize] = 0;
+        termios.control_chars[SpecialCharacterIndices::VTIME as usize] = 0;
+        tcsetattr(&stdin, SetArg::TCSANOW, &termios)?;
+
+        Ok(Some(Self { saved }))
+    }
+
+    fn pressed(&self) -> io::Result<Vec<u8>> {
+        let mut keys = [0; 16];
+        let read = io::stdin().read(&mut keys)?;
+        Ok(keys[..read].to_vec())
+    }
+}
+
+impl Drop for RawKeys {
+    fn drop(&mut self) {
+        let _ = tcsetattr(io::stdin(), SetArg::TCSANOW, &self.saved);
+    }
+}
+
+/// Draws `frames` over each other at `fps` until they run out or `q` is
+/// pressed.
+pub fn play(frames: impl IntoIterator<Item = Frame>, fps: u32) -> io::Result<()> {
+    let keys = RawKeys::new()?;
+    let tick = Duration::from_secs(1) / fps.max(1);
+    let mut frames = frames.into_iter();
+    let mut out = io::stdout().lock();
+
+    let (mut paused, mut fast, mut step) = (false, false, false);
+    let mut frame = None;
+    write!(out, "\x1b[2J")?;
+
+    loop {
+        let mut redraw = false;
+        if let Some(keys) = &keys {
+            for key in keys.pressed()? {
+                match key {
+                    b' ' => paused = !paused,
+                    b'n' => step = true,
+                    b'f' => fast = !fast,
+                    b'q' => return Ok(()),
+                    _ => continue,
+                }
+                redraw = true;
+            }
+        }
+
+        if !paused || step {
+            step = false;
+            let skip = if fast { FAST_FORWARD } else { 1 };
+            match frames.by_ref().take(skip).last() {
+                Some(next) => frame = Some(next),
+                None => return Ok(()),
+            }
+            redraw = true;
+        }
+
+        if let Some(frame) = frame.as_ref().filter(|_| redraw) {
+            // home the cursor and draw over the last frame, clearing what's left of it
+            write!(out, "\x1b[H{frame}\x1b[J")?;
+            if keys.is_some() {
+                let state = match (paused, fast) {
+                    (true, _) => "paused",
+                    (_, true) => "fast-forward",
+                    _ => "playing",
+                };
+                writeln!(out, "[{state}] {KEYS}")?;
+            }
+            out.flush()?;
+        }
+
+        thread::sleep(tick);
+    }
+}
@@ -0,0 +1,39 @@
+//! Compiles the C solutions against the stub Pico SDK in `stub/`.
+//!
+//! Every day names its entry points `part1`/`part2`, so each one gets its
+//! own archive with those (and `main`) renamed to `cNN_<name>`.
+
+use std::path::Path;
+
+const DAYS: &[(&str, &[&str])] = &[
+    ("c01", &["part1", "part2"]),
+    ("c02", &["count_safe_levels"]),
+    ("c03", &["part1", "part2"]),
+    ("c04", &["part1", "part2"]),
+    ("c05", &["check_updates"]),
+    ("c07", &["part1"]),
+    ("c08", &["assess_nodes", "part1", "part2"]),
+    ("c11", &["part1"]),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=stub");
+
+    for (day, entries) in DAYS {
+        let source = Path::new("..").join(day).join("blink.c");
+        println!("cargo:rerun-if-changed={}", source.display());
+
+        let mut build = cc::Build::new();
+        build
+            .file(&source)
+            .include("stub")
+            // they were written for the Pico's gcc and it shows
+            .warnings(false)
+            .flag_if_supported("-w")
+            .define("main", format!("{day}_main").as_str());
+        for entry in *entries {
+            build.define(entry, format!("{day}_{entry}").as_str());
+        }
+        build.compile(day);
+    }
+}
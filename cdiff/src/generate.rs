@@ -0,0 +1,216 @@
+//! Random inputs in the shape of the real ones.
+//!
+//! They stay inside what both sides were written for: the C parsers assume
+//! well formed input and the buffers on both sides are fixed size, so a
+//! generator never makes anything the puzzle itself wouldn't.
+
+use aoc_core::rng::Rng;
+use std::fmt::Write;
+
+/// A random input for `day`, or `None` if there is no generator for it.
+pub fn input(day: u8, rng: &mut Rng) -> Option<String> {
+    let input = match day {
+        1 => location_lists(rng),
+        2 => reports(rng),
+        3 => memory(rng),
+        4 => word_search(rng),
+        5 => print_queue(rng),
+        7 => equations(rng),
+        8 => antenna_map(rng),
+        11 => stones(rng),
+        _ => return None,
+    };
+    Some(input)
+}
+
+fn location_lists(rng: &mut Rng) -> String {
+    let lines = rng.range(1, 1000) as usize;
+    let mut left = Vec::with_capacity(lines);
+    let mut input = String::new();
+
+    for _ in 0..lines {
+        let id = rng.range(10_000, 99_999);
+        left.push(id);
+        // reuse a left hand id now and then or part 2 is always 0
+        let other = if rng.one_in(3) {
+            *rng.pick(&left)
+        } else {
+            rng.range(10_000, 99_999)
+        };
+        writeln!(input, "{id}   {other}").unwrap();
+    }
+    input
+}
+
+fn reports(rng: &mut Rng) -> String {
+    let mut input = String::new();
+
+    for _ in 0..rng.range(1, 1000) {
+        let increasing = rng.one_in(2);
+        // eight steps of at most 6 can't take it below zero
+        let mut level = 50i64;
+        let mut levels = vec![level];
+        for _ in 1..rng.range(5, 8) {
+            let step = if rng.one_in(8) {
+                rng.range(0, 6) as i64
+            } else {
+                rng.range(1, 3) as i64
+            };
+            let up = increasing != rng.one_in(10);
+            level += if up { step } else { -step };
+            levels.push(level);
+        }
+
+        let levels: Vec<_> = levels.iter().map(i64::to_string).collect();
+        writeln!(input, "{}", levels.join(" ")).unwrap();
+    }
+    input
+}
+
+fn memory(rng: &mut Rng) -> String {
+    const NOISE: &[&str] = &[
+        "x",
+        "?",
+        "!",
+        "^",
+        "&",
+        "[",
+        "]",
+        "(",
+        ")",
+        ",",
+        " ",
+        "'",
+        "+",
+        "how",
+        "who",
+        "what",
+        "select",
+        "from",
+        "mul",
+        "mul(",
+        "do",
+        "don't",
+        "mul[3,7]",
+        "mul ( 2 , 4 )",
+        "undo()",
+    ];
+    let mut input = String::new();
+
+    for _ in 0..rng.range(1, 6) {
+        for _ in 0..rng.range(10, 400) {
+            let a = rng.range(1, 999);
+            let b = rng.range(1, 999);
+            match rng.range(0, 9) {
+                0..=2 => write!(input, "mul({a},{b})").unwrap(),
+                3 => write!(input, "mul({a},{b}]").unwrap(),
+                4 => input.push_str("do()"),
+                5 => input.push_str("don't()"),
+                _ => {
+                    let noise = *rng.pick(NOISE);
+                    input.push_str(noise);
+                }
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn word_search(rng: &mut Rng) -> String {
+    let rows = rng.range(4, 140);
+    let cols = rng.range(4, 140);
+    let mut input = String::new();
+
+    for _ in 0..rows {
+        for _ in 0..cols {
+            input.push(*rng.pick(&['X', 'M', 'A', 'S']));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn print_queue(rng: &mut Rng) -> String {
+    // the rules give every pair of pages an order, like the real input does
+    let mut pages: Vec<u64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(rng.range(3, 49) as usize);
+
+    let mut rules = Vec::new();
+    for (idx, before) in pages.iter().enumerate() {
+        for after in &pages[idx + 1..] {
+            rules.push((*before, *after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut input = String::new();
+    for (before, after) in rules {
+        writeln!(input, "{before}|{after}").unwrap();
+    }
+    input.push('\n');
+
+    for _ in 0..rng.range(1, 200) {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        let len = 2 * rng.range(1, (update.len() as u64 - 1).min(28) / 2) + 1;
+        update.truncate(len as usize);
+        // about half should already be in order for part 1 to count
+        if rng.one_in(2) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+
+        let update: Vec<_> = update.iter().map(u64::to_string).collect();
+        writeln!(input, "{}", update.join(",")).unwrap();
+    }
+    input
+}
+
+fn equations(rng: &mut Rng) -> String {
+    let mut input = String::new();
+
+    for _ in 0..rng.range(1, 850) {
+        let terms: Vec<u64> = (0..rng.range(2, 7)).map(|_| rng.range(1, 99)).collect();
+        // make most of them solvable, with concatenation or without
+        let mut target = terms[0];
+        for term in &terms[1..] {
+            target = match rng.range(0, 2) {
+                0 => target + term,
+                1 => target * term,
+                _ => format!("{target}{term}").parse().unwrap(),
+            };
+        }
+        if rng.one_in(3) {
+            target += rng.range(1, 9);
+        }
+
+        let terms: Vec<_> = terms.iter().map(u64::to_string).collect();
+        writeln!(input, "{target}: {}", terms.join(" ")).unwrap();
+    }
+    input
+}
+
+fn antenna_map(rng: &mut Rng) -> String {
+    // both sides keep at most 1000 antinodes, which a 31x31 map can't exceed
+    let size = rng.range(5, 31) as usize;
+    let mut map = vec![vec!['.'; size]; size];
+    let frequencies: Vec<char> = "aA0zZ9".chars().take(rng.range(1, 6) as usize).collect();
+
+    for _ in 0..rng.range(2, 40) {
+        let row = rng.range(0, size as u64 - 1) as usize;
+        let col = rng.range(0, size as u64 - 1) as usize;
+        map[row][col] = *rng.pick(&frequencies);
+    }
+
+    map.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+fn stones(rng: &mut Rng) -> String {
+    let stones: Vec<_> = (0..rng.range(1, 8))
+        .map(|_| rng.range(0, 999_999).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
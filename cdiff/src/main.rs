@@ -0,0 +1,91 @@
+use aoc_core::rng::Rng;
+use cdiff::{DAYS, compare, generate};
+use clap::Parser;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Check that the C and Rust solutions give the same answers")]
+struct Cli {
+    /// Only check this day, otherwise every day with both solutions
+    #[arg(long)]
+    day: Option<u8>,
+    /// Compare on this puzzle input instead of generated ones (needs --day)
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// How many generated inputs to try per day
+    #[arg(long, default_value_t = 100)]
+    rounds: u64,
+    /// Seed of the first generated input, the rest follow on from it
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Print the generated input whenever the answers differ
+    #[arg(long)]
+    show: bool,
+}
+
+/// Prints what `compare` found and says whether it was anything new.
+fn report(day: u8, input: &str, source: &str, show: bool) -> bool {
+    let divergences = match compare(day, input) {
+        Ok(divergences) => divergences,
+        Err(message) => {
+            eprintln!("{source}: {message}");
+            return false;
+        }
+    };
+
+    let mut fine = true;
+    for divergence in &divergences {
+        let known = if divergence.is_known() {
+            " (known)"
+        } else {
+            fine = false;
+            ""
+        };
+        println!("{source}: {divergence}{known}");
+    }
+    if show && !divergences.is_empty() {
+        print!("{input}");
+    }
+    fine
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let fine = if let Some(path) = cli.input {
+        let day = cli.day.unwrap();
+        let input = match std::fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("can't read {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        };
+        report(day, &input, &path.display().to_string(), cli.show)
+    } else {
+        let days = match cli.day {
+            Some(day) if !DAYS.contains(&day) => {
+                eprintln!("day {day} doesn't have both a C and a Rust solution");
+                return ExitCode::FAILURE;
+            }
+            Some(day) => vec![day],
+            None => DAYS.to_vec(),
+        };
+
+        let mut fine = true;
+        for day in days {
+            for seed in cli.seed..cli.seed + cli.rounds {
+                let input = generate::input(day, &mut Rng::new(seed)).unwrap();
+                fine &= report(day, &input, &format!("seed {seed}"), cli.show);
+            }
+        }
+        fine
+    };
+
+    if fine {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
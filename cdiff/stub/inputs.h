@@ -0,0 +1,9 @@
+// Stands in for the header cinputs generates. The harness hands the input
+// to part1/part2 itself, so the arrays main() would read are left empty.
+#ifndef INPUTS_H
+#define INPUTS_H
+
+static char input[] = "";
+static char example1[] = "";
+
+#endif
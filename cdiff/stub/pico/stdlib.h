@@ -0,0 +1,24 @@
+// Just enough of the Pico SDK for the cNN/blink.c files to compile on the
+// host. Their main() is renamed out of the way, so none of this ever runs.
+#ifndef PICO_STDLIB_H
+#define PICO_STDLIB_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+typedef unsigned int uint;
+
+#define GPIO_OUT 1
+
+static inline bool stdio_init_all(void) { return true; }
+static inline void gpio_init(uint gpio) { (void)gpio; }
+static inline void gpio_set_dir(uint gpio, bool out) {
+    (void)gpio;
+    (void)out;
+}
+static inline void gpio_put(uint gpio, bool value) {
+    (void)gpio;
+    (void)value;
+}
+
+#endif
@@ -28,4 +28,7 @@ fn main() {
     // here, we ensure the build script is only re-run when
     // `memory.x` is changed.
     println!("cargo:rerun-if-changed=memory.x");
+
+    // The puzzle input, normalised, for `src/main.rs` to `include!`.
+    aoc_embed::embed_input("input.txt");
 }
@@ -2,67 +2,55 @@
 #![no_main]
 
 use bsp::entry;
+use bsp::hal::{clocks::init_clocks_and_plls, pac, Timer, Watchdog};
 use defmt::*;
 use defmt_rtt as _;
 use panic_probe as _;
 use rp_pico as bsp;
 
-use core::hint::black_box;
-use core::str;
-use heapless::Vec;
+use aoc_core::report::report_answers;
+use aoc_core::stack::{paint_stack, stack_high_water};
+use aoc_core::storage::Heapless;
+use aoc_core::timing::{Stopwatch, Timings};
+use r01_lib::{part1, part2, process_inputs};
 
-const MAXLEN: usize = 1001;
-
-fn process_inputs(input: &str) -> (Vec<i32, MAXLEN>, Vec<i32, MAXLEN>) {
-    let mut vec1: Vec<i32, MAXLEN> = Vec::new();
-    let mut vec2: Vec<i32, MAXLEN> = Vec::new();
-
-    for line in input.lines() {
-        let mut split = line.split("   ");
-        let left = split
-            .next()
-            .expect("expected two tab seperated number columns");
-        let right = split
-            .next()
-            .expect("expected two tab seperated number columns");
-
-        let _ = vec1.push(left.parse().unwrap());
-        let _ = vec2.push(right.parse().unwrap());
-    }
-
-    (vec1, vec2)
-}
-
-fn part1(mut vec1: Vec<i32, MAXLEN>, mut vec2: Vec<i32, MAXLEN>) -> i32 {
-    vec1.sort_unstable();
-    vec2.sort_unstable();
-
-    vec1.into_iter()
-        .zip(vec2)
-        .map(|(a, b)| (a - b).abs())
-        .sum::<i32>()
-}
-
-fn part2(vec1: &Vec<i32, MAXLEN>, vec2: &Vec<i32, MAXLEN>) -> i32 {
-    vec1.iter()
-        .map(|&i| vec2.iter().filter(|&x| *x == i).count() as i32 * i)
-        .sum()
-}
+include!(concat!(env!("OUT_DIR"), "/input.rs"));
 
 #[entry]
 fn main() -> ! {
     info!("Program start");
-
-    let inputs = include_str!("../input.txt");
-    let (vec1, vec2) = process_inputs(inputs);
-
-    let ans2 = part2(&vec1, &vec2);
-    let ans1 = part1(vec1, vec2);
+    paint_stack();
+
+    let mut pac = pac::Peripherals::take().unwrap();
+    let mut watchdog = Watchdog::new(pac.WATCHDOG);
+    let clocks = init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+    let timer = Timer::new(pac.TIMER, &mut pac.RESETS, &clocks);
+    let clock = || timer.get_counter().ticks();
+
+    let mut stopwatch = Stopwatch::start(&clock);
+    let (left, right) = unwrap!(process_inputs::<Heapless>(INPUT));
+    let parse_us = stopwatch.lap();
+    let ans1 = part1(&left, &right);
+    let part1_us = stopwatch.lap();
+    let ans2 = part2(&left, &right);
+    let part2_us = stopwatch.lap();
     info!("calculation finished");
 
-    // forcing the compiler to keeps these alive so I can view them
-    // with the debugger
-    black_box(ans1);
-    black_box(ans2);
+    let timings = Timings {
+        parse_us,
+        part1_us,
+        part2_us,
+    };
+    report_answers(1, ans1, ans2, timings, stack_high_water());
     loop {}
 }
@@ -0,0 +1,25 @@
+pub mod report;
+
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::storage::Alloc;
+use r01_lib::{Column, part1, part2, process_inputs};
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = (Column<Alloc>, Column<Alloc>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        process_inputs::<Alloc>(input.as_bytes())
+    }
+
+    fn part1(&self, (left, right): &Self::Input) -> Result<Answer, InputError> {
+        Ok(part1(left, right).into())
+    }
+
+    fn part2(&self, (left, right): &Self::Input) -> Result<Answer, InputError> {
+        Ok(part2(left, right).into())
+    }
+}
@@ -0,0 +1,157 @@
+//! The tables behind the day 1 answers, for checking an input by eye or
+//! pulling into a spreadsheet. Each is built from the same sorted columns
+//! `part1` and `part2` use, so they add up to the answers.
+
+use serde::Serialize;
+use std::cmp::Reverse;
+
+/// A row of one of the tables, which can be written out as CSV as well as
+/// JSON.
+pub trait Row: Serialize {
+    /// The column names, in the order `csv` writes them.
+    const HEADER: &'static str;
+
+    fn csv(&self) -> String;
+}
+
+/// The `index`th smallest numbers of each column, and how far apart they
+/// are. The distances add up to part 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Pair {
+    pub index: usize,
+    pub left: u32,
+    pub right: u32,
+    pub distance: u32,
+}
+
+impl Row for Pair {
+    const HEADER: &'static str = "index,left,right,distance";
+
+    fn csv(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            self.index, self.left, self.right, self.distance
+        )
+    }
+}
+
+/// A number from the right column, how often it turns up in each, and what
+/// that adds to the similarity score. The scores add up to part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Count {
+    pub value: u32,
+    pub right: usize,
+    pub left: usize,
+    pub score: u64,
+}
+
+impl Row for Count {
+    const HEADER: &'static str = "value,right,left,score";
+
+    fn csv(&self) -> String {
+        format!("{},{},{},{}", self.value, self.right, self.left, self.score)
+    }
+}
+
+/// Both columns have to be sorted.
+pub fn pairs(left: &[u32], right: &[u32]) -> Vec<Pair> {
+    left.iter()
+        .zip(right)
+        .enumerate()
+        .map(|(index, (&left, &right))| Pair {
+            index,
+            left,
+            right,
+            distance: left.abs_diff(right),
+        })
+        .collect()
+}
+
+/// The `top` pairs furthest apart, widest first, with ties left in order.
+pub fn widest(pairs: &[Pair], top: usize) -> Vec<Pair> {
+    let mut widest = pairs.to_vec();
+    widest.sort_by_key(|pair| Reverse(pair.distance));
+    widest.truncate(top);
+    widest
+}
+
+/// Every different number in the right column, smallest first. Both
+/// columns have to be sorted, so the left can be counted in the same pass.
+pub fn counts(left: &[u32], right: &[u32]) -> Vec<Count> {
+    let mut counts = Vec::new();
+    let mut l = 0;
+
+    for run in right.chunk_by(|a, b| a == b) {
+        let value = run[0];
+        l += left[l..].iter().take_while(|&&x| x < value).count();
+        let lefts = left[l..].iter().take_while(|&&x| x == value).count();
+        l += lefts;
+        counts.push(Count {
+            value,
+            right: run.len(),
+            left: lefts,
+            score: u64::from(value) * (lefts * run.len()) as u64,
+        });
+    }
+
+    counts
+}
+
+/// `rows` as CSV, with a header line, each line ending in a newline.
+pub fn csv<R: Row>(rows: &[R]) -> String {
+    let mut csv = format!("{}\n", R::HEADER);
+    for row in rows {
+        csv.push_str(&row.csv());
+        csv.push('\n');
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use r01_lib::{part1, part2};
+
+    // the example, sorted
+    const LEFT: [u32; 6] = [1, 2, 3, 3, 3, 4];
+    const RIGHT: [u32; 6] = [3, 3, 3, 4, 5, 9];
+
+    #[test]
+    fn test_pairs() {
+        let pairs = pairs(&LEFT, &RIGHT);
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(
+            pairs[5],
+            Pair {
+                index: 5,
+                left: 4,
+                right: 9,
+                distance: 5,
+            }
+        );
+        let total: u64 = pairs.iter().map(|pair| u64::from(pair.distance)).sum();
+        assert_eq!(total, part1(&LEFT, &RIGHT));
+
+        let widest: Vec<usize> = widest(&pairs, 3).iter().map(|pair| pair.index).collect();
+        // 0 and 4 are both 2 apart
+        assert_eq!(widest, [5, 0, 4]);
+    }
+
+    #[test]
+    fn test_counts() {
+        let counts = counts(&LEFT, &RIGHT);
+        let values: Vec<(u32, usize, usize)> = counts
+            .iter()
+            .map(|count| (count.value, count.right, count.left))
+            .collect();
+        assert_eq!(values, [(3, 3, 3), (4, 1, 1), (5, 1, 0), (9, 1, 0)]);
+        let total: u64 = counts.iter().map(|count| count.score).sum();
+        assert_eq!(total, part2(&LEFT, &RIGHT));
+    }
+
+    #[test]
+    fn test_csv() {
+        let pairs = pairs(&LEFT[..2], &RIGHT[..2]);
+        assert_eq!(csv(&pairs), "index,left,right,distance\n0,1,3,2\n1,2,3,1\n");
+    }
+}
@@ -0,0 +1,193 @@
+#![no_std]
+
+use aoc_core::error::{InputError, OrFull};
+use aoc_core::parse::Expected;
+use aoc_core::scan::Scanner;
+use aoc_core::storage::{List, Storage};
+use core::cmp::Ordering;
+
+/// Only binds the firmware; the host's columns grow as needed.
+pub const MAXLEN: usize = 1001;
+
+pub type Column<S> = <S as Storage>::Vec<u32, MAXLEN>;
+
+/// The two columns, read a line at a time so they can be fed as the input
+/// arrives, from a UART say, rather than all at once.
+pub struct Lists<S: Storage> {
+    left: Column<S>,
+    right: Column<S>,
+    line: usize,
+}
+
+impl<S: Storage> Default for Lists<S> {
+    fn default() -> Self {
+        Self {
+            left: Default::default(),
+            right: Default::default(),
+            line: 0,
+        }
+    }
+}
+
+impl<S: Storage> Lists<S> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the next line of two space separated numbers. The newline on
+    /// the end is optional, and a `\r` before it is let off.
+    pub fn push_line(&mut self, line: &[u8]) -> Result<(), InputError> {
+        self.line += 1;
+        let line = line.strip_suffix(b"\n").unwrap_or(line);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        let mut scan = Scanner::on_line(self.line, line);
+        let left = scan.number()?;
+        scan.spaces()?;
+        let right = scan.number()?;
+        if !scan.is_empty() {
+            return Err(scan.error(Expected::Thing("the end of the line")).into());
+        }
+
+        self.left
+            .push(left)
+            .or_full("left column", MAXLEN, self.line)?;
+        self.right
+            .push(right)
+            .or_full("right column", MAXLEN, self.line)?;
+        Ok(())
+    }
+
+    /// Both columns, sorted, which is how both parts want them.
+    pub fn finish(mut self) -> (Column<S>, Column<S>) {
+        self.left.sort_unstable();
+        self.right.sort_unstable();
+        (self.left, self.right)
+    }
+}
+
+/// Reads the two space separated number columns, and sorts them.
+pub fn process_inputs<S: Storage>(input: &[u8]) -> Result<(Column<S>, Column<S>), InputError> {
+    let mut lists = Lists::<S>::new();
+    // the last newline ends a line rather than starting an empty one
+    for line in input.split_inclusive(|&byte| byte == b'\n') {
+        lists.push_line(line)?;
+    }
+    Ok(lists.finish())
+}
+
+/// Both columns have to be sorted.
+pub fn part1(left: &[u32], right: &[u32]) -> u64 {
+    left.iter()
+        .zip(right)
+        .map(|(a, b)| u64::from(a.abs_diff(*b)))
+        .sum()
+}
+
+/// Both columns have to be sorted, so each value's run on the left can be
+/// matched against its run on the right in one pass over the two.
+pub fn part2(left: &[u32], right: &[u32]) -> u64 {
+    let (mut l, mut r) = (0, 0);
+    let mut score = 0;
+
+    while l < left.len() && r < right.len() {
+        match left[l].cmp(&right[r]) {
+            Ordering::Less => l += 1,
+            Ordering::Greater => r += 1,
+            Ordering::Equal => {
+                let value = left[l];
+                let lefts = left[l..].iter().take_while(|&&x| x == value).count();
+                let rights = right[r..].iter().take_while(|&&x| x == value).count();
+                score += u64::from(value) * (lefts * rights) as u64;
+                l += lefts;
+                r += rights;
+            }
+        }
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use aoc_core::error::CapacityError;
+    use aoc_core::parse::{Found, ParseError};
+    use aoc_core::storage::{Alloc, Heapless};
+    use std::string::String;
+
+    #[test]
+    fn test_too_many_lines() {
+        let input: String = (0..MAXLEN + 1).map(|_| "3   4\n").collect();
+        let err = process_inputs::<Heapless>(input.as_bytes()).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Capacity(CapacityError {
+                buffer: "left column",
+                capacity: MAXLEN,
+                line: MAXLEN + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_limit_on_host() {
+        let input: String = (0..MAXLEN * 3).map(|n| std::format!("{n}   3\n")).collect();
+        let (left, right) = process_inputs::<Alloc>(input.as_bytes()).unwrap();
+        assert_eq!((left.len(), right.len()), (MAXLEN * 3, MAXLEN * 3));
+        // 3 is on the left once, and every number on the right
+        assert_eq!(part2(&left, &right), 3 * (MAXLEN as u64 * 3));
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let err = process_inputs::<Heapless>(b"3   4\n4\n").unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Parse(ParseError {
+                line: 2,
+                column: 2,
+                expected: Expected::Thing("a space"),
+                found: Found::EndOfLine,
+            })
+        );
+    }
+
+    #[test]
+    fn test_line_at_a_time() {
+        let mut lists = Lists::<Heapless>::new();
+        for line in [
+            "3   4\r\n",
+            "4   3\n",
+            "2   5",
+            "1   3\n",
+            "3   9\n",
+            "3   3\n",
+        ] {
+            lists.push_line(line.as_bytes()).unwrap();
+        }
+        let err = lists.push_line(b"1   2 3\n").unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Parse(ParseError {
+                line: 7,
+                column: 6,
+                expected: Expected::Thing("the end of the line"),
+                found: Found::Char(' '),
+            })
+        );
+
+        let (left, right) = lists.finish();
+        assert_eq!((part1(&left, &right), part2(&left, &right)), (11, 31));
+    }
+
+    #[test]
+    fn test_similarity_runs() {
+        let left = [1, 2, 2, 5, 7, 7, 7];
+        let right = [2, 2, 2, 3, 7, 7, 9];
+        // 2 twice, seen three times, and 7 three times, seen twice
+        assert_eq!(part2(&left, &right), 2 * 2 * 3 + 7 * 3 * 2);
+    }
+}
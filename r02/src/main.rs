@@ -2,83 +2,55 @@
 #![no_main]
 
 use bsp::entry;
+use bsp::hal::{clocks::init_clocks_and_plls, pac, Timer, Watchdog};
 use defmt::*;
 use defmt_rtt as _;
 use panic_probe as _;
 use rp_pico as bsp;
 
-use core::hint::black_box;
-use core::str;
-use heapless::Vec;
+use aoc_core::report::report_answers;
+use aoc_core::stack::{paint_stack, stack_high_water};
+use aoc_core::storage::Heapless;
+use aoc_core::timing::{Stopwatch, Timings};
+use r02_lib::{count_safe_levels, Mode};
 
-const MAX_LEVELS: usize = 10;
-
-#[derive(PartialEq)]
-enum Mode {
-    Tolerant,
-    Intolerant,
-}
-
-fn count_safe_levels(input: &str, mode: Mode) -> i32 {
-    let mut safe_lines = 0;
-
-    for line in input.lines() {
-        let levels: Vec<i32, MAX_LEVELS> = line.split(' ').map(|s| s.parse().unwrap()).collect();
-
-        if assess_safety(&levels) {
-            safe_lines += 1;
-            continue;
-        }
-
-        if mode == Mode::Tolerant {
-            for to_remove in 0..levels.len() {
-                let mut remaining_levels = levels.clone();
-                remaining_levels.remove(to_remove);
-                if assess_safety(&remaining_levels) {
-                    safe_lines += 1;
-                    break;
-                }
-            }
-        }
-    }
-
-    safe_lines
-}
-
-fn assess_safety(levels: &Vec<i32, MAX_LEVELS>) -> bool {
-    let diffs: Vec<_, { MAX_LEVELS - 1 }> = levels.windows(2).map(|w| w[0] - w[1]).collect();
-
-    let max = diffs.iter().max().unwrap();
-    let min = diffs.iter().min().unwrap();
-
-    if (*min < 0) && (*max > 0) {
-        return false;
-    }
-
-    if (*min == 0) || (*max == 0) {
-        return false;
-    }
-
-    if (min.abs() > 3) || (max.abs() > 3) {
-        return false;
-    }
-
-    true
-}
+include!(concat!(env!("OUT_DIR"), "/input.rs"));
 
 #[entry]
 fn main() -> ! {
     info!("Program start");
-
-    let inputs = include_str!("../input.txt");
-
-    let ans1 = count_safe_levels(inputs, Mode::Intolerant);
-    let ans2 = count_safe_levels(inputs, Mode::Tolerant);
+    paint_stack();
+
+    let mut pac = pac::Peripherals::take().unwrap();
+    let mut watchdog = Watchdog::new(pac.WATCHDOG);
+    let clocks = init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+    let timer = Timer::new(pac.TIMER, &mut pac.RESETS, &clocks);
+    let clock = || timer.get_counter().ticks();
+
+    let mut stopwatch = Stopwatch::start(&clock);
+    // count_safe_levels parses each report as it goes
+    let parse_us = 0;
+    let ans1 = unwrap!(count_safe_levels::<Heapless>(INPUT, Mode::Intolerant));
+    let part1_us = stopwatch.lap();
+    let ans2 = unwrap!(count_safe_levels::<Heapless>(INPUT, Mode::Tolerant));
+    let part2_us = stopwatch.lap();
     info!("calculation finished");
 
-    // forcing the compiler to keeps these alive so I can view them
-    // with the debugger
-    black_box(ans1);
-    black_box(ans2);
+    let timings = Timings {
+        parse_us,
+        part1_us,
+        part2_us,
+    };
+    report_answers(2, ans1, ans2, timings, stack_high_water());
     loop {}
 }
@@ -0,0 +1,47 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::storage::Alloc;
+pub use r02_lib::{Mode, Rules, Verdict};
+use r02_lib::{count_safe_levels, judge_reports};
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input = String;
+
+    // count_safe_levels reads the reports as it goes, so a bad one turns up
+    // in whichever part gets to it first
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(count_safe_levels::<Alloc>(input.as_bytes(), Mode::Intolerant)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(count_safe_levels::<Alloc>(input.as_bytes(), Mode::Tolerant)?.into())
+    }
+}
+
+/// One line of the input, and what was made of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub line: usize,
+    pub levels: Vec<u8>,
+    pub verdict: Verdict,
+}
+
+/// Every report in `input`, for checking by hand which are safe and why.
+pub fn reports(input: &str, rules: Rules) -> Result<Vec<Report>, InputError> {
+    let mut reports = Vec::new();
+    judge_reports::<Alloc>(input.as_bytes(), rules, |line, levels, verdict| {
+        reports.push(Report {
+            line,
+            levels: levels.to_vec(),
+            verdict,
+        })
+    })?;
+    Ok(reports)
+}
@@ -0,0 +1,592 @@
+#![no_std]
+
+use aoc_core::error::{InputError, OrFull};
+use aoc_core::scan::Scanner;
+use aoc_core::storage::{List, Storage};
+use core::fmt;
+
+/// Only binds `judge_reports` on the firmware, which has to keep each
+/// report's levels to hand them over. Counting never keeps them.
+pub const MAX_LEVELS: usize = 10;
+
+/// The most levels the dampener can take out on the firmware.
+pub const MAX_TOLERANCE: usize = 3;
+
+/// A safe step can be from the level up to `tolerance` levels back.
+const WINDOW: usize = MAX_TOLERANCE + 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Tolerant,
+    Intolerant,
+}
+
+/// What makes a report safe: every step between the levels kept going the
+/// same way, by `min_step..=max_step`, with up to `tolerance` levels taken
+/// out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub min_step: u8,
+    pub max_step: u8,
+    pub tolerance: usize,
+}
+
+impl Rules {
+    /// The puzzle's steps of 1 to 3.
+    pub const fn with_tolerance(tolerance: usize) -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            tolerance,
+        }
+    }
+
+    fn step_ok(&self, from: u8, to: u8, rising: bool) -> bool {
+        let step = if rising {
+            to.checked_sub(from)
+        } else {
+            from.checked_sub(to)
+        };
+        step.is_some_and(|step| (self.min_step..=self.max_step).contains(&step))
+    }
+}
+
+impl From<Mode> for Rules {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Intolerant => Rules::with_tolerance(0),
+            Mode::Tolerant => Rules::with_tolerance(1),
+        }
+    }
+}
+
+/// What is wrong with a step from one level to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// Up after going down, or down after going up.
+    DirectionChange,
+    /// The same level twice.
+    NoChange,
+    /// Up or down by less than the smallest step.
+    TooSmall,
+    /// Up or down by more than the biggest step.
+    TooBig,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Reason::DirectionChange => "changes direction",
+            Reason::NoChange => "doesn't change",
+            Reason::TooSmall => "changes too little",
+            Reason::TooBig => "changes too much",
+        })
+    }
+}
+
+/// The first unsafe step in a report, at the level it steps to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fault {
+    pub index: usize,
+    pub reason: Reason,
+}
+
+/// How a report fared. Indices count levels from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    /// Safe once the level at `removed` is taken out, which only the
+    /// problem dampener does. With a tolerance over 1 this is the first of
+    /// the fewest levels that had to go.
+    Dampened {
+        removed: usize,
+    },
+    /// Unsafe even with levels taken out, if the dampener was on. The
+    /// fault is the one in the whole report.
+    Unsafe(Fault),
+}
+
+impl Verdict {
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Verdict::Unsafe(_))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // counted from 1 for people reading them
+        match self {
+            Verdict::Safe => write!(f, "safe"),
+            Verdict::Dampened { removed } => write!(f, "safe without level {}", removed + 1),
+            Verdict::Unsafe(fault) => {
+                write!(f, "unsafe, level {} {}", fault.index + 1, fault.reason)
+            }
+        }
+    }
+}
+
+/// The cheapest way found to keep a level as the last so far, going one
+/// way: how many levels went before it, and the first of them. Cheaper
+/// sorts first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Path {
+    removed: usize,
+    first: Option<usize>,
+}
+
+impl Path {
+    /// This path, then `gap` more levels taken out starting at `from`.
+    fn skip(self, gap: usize, from: usize) -> Self {
+        Path {
+            removed: self.removed + gap,
+            first: self.first.or((gap > 0).then_some(from)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Kept {
+    index: usize,
+    level: u8,
+    rising: Option<Path>,
+    falling: Option<Path>,
+}
+
+/// Decides whether a report is safe a level at a time, in one pass.
+///
+/// A level can only be kept after one of the `tolerance + 1` before it,
+/// with the ones between taken out, so only those need remembering, along
+/// with the cheapest way to keep each going up and going down. That makes
+/// it linear in the levels for any tolerance, and the report itself never
+/// has to be stored.
+pub struct Checker<S: Storage> {
+    rules: Rules,
+    window: S::Vec<Kept, WINDOW>,
+    levels: usize,
+}
+
+impl<S: Storage> Checker<S> {
+    pub fn new(rules: impl Into<Rules>) -> Self {
+        Self {
+            rules: rules.into(),
+            window: Default::default(),
+            levels: 0,
+        }
+    }
+
+    /// Takes the next level. Hands it back if the tolerance is more than
+    /// the storage can look back over.
+    pub fn push(&mut self, level: u8) -> Result<(), u8> {
+        let index = self.levels;
+        let tolerance = self.rules.tolerance;
+        // or keep this as the first, with all those before it taken out
+        let start = (index <= tolerance).then(|| Path {
+            removed: index,
+            first: (index > 0).then_some(0),
+        });
+        let mut kept = Kept {
+            index,
+            level,
+            rising: start,
+            falling: start,
+        };
+
+        for before in self.window.iter() {
+            let gap = index - before.index - 1;
+            for (rising, to, from) in [
+                (true, &mut kept.rising, before.rising),
+                (false, &mut kept.falling, before.falling),
+            ] {
+                let Some(from) = from else { continue };
+                if !self.rules.step_ok(before.level, level, rising) {
+                    continue;
+                }
+                let path = from.skip(gap, before.index + 1);
+                if path.removed <= tolerance && to.is_none_or(|to| path < to) {
+                    *to = Some(path);
+                }
+            }
+        }
+
+        if self.window.len() > tolerance {
+            self.window.remove(0);
+        }
+        self.window.push(kept).map_err(|_| level)?;
+        self.levels += 1;
+        Ok(())
+    }
+
+    /// `None` if the levels so far are unsafe even with `tolerance` of them
+    /// taken out, otherwise the first that had to go, if any did.
+    pub fn removed(&self) -> Option<Option<usize>> {
+        let last = self.levels.checked_sub(1)?;
+        self.window
+            .iter()
+            .flat_map(|kept| {
+                [kept.rising, kept.falling]
+                    .into_iter()
+                    .flatten()
+                    .map(|path| path.skip(last - kept.index, kept.index + 1))
+            })
+            .filter(|path| path.removed <= self.rules.tolerance)
+            .min()
+            .map(|path| path.first)
+    }
+
+    pub fn is_safe(&self) -> bool {
+        self.removed().is_some()
+    }
+
+    /// Starts on the next report.
+    pub fn clear(&mut self) {
+        self.window.clear();
+        self.levels = 0;
+    }
+}
+
+/// Reads a report's levels off the line `scan` is on, a level at a time.
+fn read_report(
+    scan: &mut Scanner,
+    mut each: impl FnMut(u8) -> Result<(), InputError>,
+) -> Result<(), InputError> {
+    loop {
+        each(scan.number()?)?;
+        if scan.at_line_end() {
+            break;
+        }
+        scan.tag(" ")?;
+    }
+    scan.end_line()?;
+    Ok(())
+}
+
+/// Reads each report in turn, handing `each` its line number, levels and
+/// verdict.
+pub fn judge_reports<S: Storage>(
+    input: &[u8],
+    rules: impl Into<Rules>,
+    mut each: impl FnMut(usize, &[u8], Verdict),
+) -> Result<(), InputError> {
+    let rules = rules.into();
+    let mut checker = Checker::<S>::new(rules);
+    let mut scan = Scanner::new(input);
+
+    while !scan.is_empty() {
+        let line = scan.line();
+        // levels are all under 100, so a byte each is plenty
+        let mut levels: S::Vec<u8, MAX_LEVELS> = Default::default();
+        checker.clear();
+        read_report(&mut scan, |level| {
+            levels.push(level).or_full("levels", MAX_LEVELS, line)?;
+            checker
+                .push(level)
+                .or_full("dampener window", WINDOW, line)?;
+            Ok(())
+        })?;
+
+        each(line, &levels, verdict(&levels, &checker, &rules));
+    }
+
+    Ok(())
+}
+
+pub fn count_safe_levels<S: Storage>(
+    input: &[u8],
+    rules: impl Into<Rules>,
+) -> Result<i32, InputError> {
+    let mut checker = Checker::<S>::new(rules);
+    let mut safe_lines = 0;
+    let mut scan = Scanner::new(input);
+
+    while !scan.is_empty() {
+        let line = scan.line();
+        checker.clear();
+        read_report(&mut scan, |level| {
+            Ok(checker
+                .push(level)
+                .or_full("dampener window", WINDOW, line)?)
+        })?;
+
+        if checker.is_safe() {
+            safe_lines += 1;
+        }
+    }
+
+    Ok(safe_lines)
+}
+
+/// The verdict on one report, or `None` if the tolerance is more than the
+/// storage can look back over.
+pub fn judge<S: Storage>(levels: &[u8], rules: impl Into<Rules>) -> Option<Verdict> {
+    let rules = rules.into();
+    let mut checker = Checker::<S>::new(rules);
+    for &level in levels {
+        checker.push(level).ok()?;
+    }
+    Some(verdict(levels, &checker, &rules))
+}
+
+fn verdict<S: Storage>(levels: &[u8], checker: &Checker<S>, rules: &Rules) -> Verdict {
+    match checker.removed() {
+        Some(None) => Verdict::Safe,
+        Some(Some(removed)) => Verdict::Dampened { removed },
+        // a report the checker turns down always has a fault
+        None => Verdict::Unsafe(find_fault(levels, rules).unwrap()),
+    }
+}
+
+/// Whether the levels are safe as they are, with steps of 1 to 3.
+pub fn assess_safety(levels: &[u8]) -> bool {
+    find_fault(levels, &Rules::with_tolerance(0)).is_none()
+}
+
+/// The first step that goes wrong, if any. The first step that goes
+/// anywhere sets the direction the rest have to follow.
+fn find_fault(levels: &[u8], rules: &Rules) -> Option<Fault> {
+    let mut rising = None;
+
+    for (index, pair) in levels.windows(2).enumerate() {
+        let (from, to) = (pair[0], pair[1]);
+        let step = from.abs_diff(to);
+
+        let reason = if step != 0 && *rising.get_or_insert(to > from) != (to > from) {
+            Reason::DirectionChange
+        } else if step < rules.min_step {
+            if step == 0 {
+                Reason::NoChange
+            } else {
+                Reason::TooSmall
+            }
+        } else if step > rules.max_step {
+            Reason::TooBig
+        } else {
+            continue;
+        };
+        return Some(Fault {
+            index: index + 1,
+            reason,
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use aoc_core::error::CapacityError;
+    use aoc_core::parse::{Expected, Found, ParseError};
+    use aoc_core::storage::{Alloc, Heapless};
+
+    #[test]
+    fn test_too_many_levels() {
+        let input = "7 6 4 2 1\n1 2 3 4 5 6 7 8 9 10 11\n";
+        let err = judge_reports::<Heapless>(input.as_bytes(), Mode::Intolerant, |_, _, _| {})
+            .unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Capacity(CapacityError {
+                buffer: "levels",
+                capacity: MAX_LEVELS,
+                line: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_bad_level() {
+        let err = count_safe_levels::<Heapless>(b"7 6 4\n1 2 x 4\n", Mode::Tolerant).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Parse(ParseError {
+                line: 2,
+                column: 5,
+                expected: Expected::Thing("a number"),
+                found: Found::Char('x'),
+            })
+        );
+    }
+
+    fn unsafe_at(index: usize, reason: Reason) -> Verdict {
+        Verdict::Unsafe(Fault { index, reason })
+    }
+
+    #[test]
+    fn test_example_verdicts() {
+        let input = b"7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+        let mut verdicts = [None; 6];
+        judge_reports::<Heapless>(input, Mode::Tolerant, |line, _, verdict| {
+            verdicts[line - 1] = Some(verdict);
+        })
+        .unwrap();
+        assert_eq!(
+            verdicts.map(Option::unwrap),
+            [
+                Verdict::Safe,
+                unsafe_at(2, Reason::TooBig),
+                unsafe_at(3, Reason::TooBig),
+                Verdict::Dampened { removed: 1 },
+                Verdict::Dampened { removed: 2 },
+                Verdict::Safe,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reasons() {
+        let judge = |levels: &[u8]| judge::<Heapless>(levels, Mode::Intolerant).unwrap();
+        assert_eq!(judge(&[5]), Verdict::Safe);
+        assert_eq!(judge(&[5, 5]), unsafe_at(1, Reason::NoChange));
+        assert_eq!(judge(&[1, 5]), unsafe_at(1, Reason::TooBig));
+        assert_eq!(judge(&[1, 2, 1]), unsafe_at(2, Reason::DirectionChange));
+        // going the wrong way counts before how far
+        assert_eq!(judge(&[9, 8, 20]), unsafe_at(2, Reason::DirectionChange));
+        assert_eq!(judge(&[1, 4, 7, 10, 13]), Verdict::Safe);
+    }
+
+    #[test]
+    fn test_dampener() {
+        let judge = |levels: &[u8]| judge::<Heapless>(levels, Mode::Tolerant).unwrap();
+        // the fault shows at level 2, but it's level 0 that set the direction
+        assert_eq!(judge(&[3, 4, 3, 2, 1]), Verdict::Dampened { removed: 0 });
+        assert_eq!(judge(&[5, 5]), Verdict::Dampened { removed: 0 });
+        assert_eq!(judge(&[1, 2, 3, 4, 9]), Verdict::Dampened { removed: 4 });
+        // the fault is reported from the whole report
+        assert_eq!(judge(&[1, 2, 2, 2]), unsafe_at(2, Reason::NoChange));
+        assert_eq!(judge(&[1, 5, 9]), unsafe_at(1, Reason::TooBig));
+    }
+
+    #[test]
+    fn test_display() {
+        let verdicts = [
+            Verdict::Safe,
+            Verdict::Dampened { removed: 0 },
+            unsafe_at(2, Reason::DirectionChange),
+        ];
+        assert_eq!(
+            verdicts.map(|verdict| std::format!("{verdict}")),
+            [
+                "safe",
+                "safe without level 1",
+                "unsafe, level 3 changes direction"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_counting_keeps_no_levels() {
+        // far more levels than MAX_LEVELS, with the 1 in the middle a step
+        // too far either side
+        let input = "2 3 4 5 6 7 8 9 10 11 12 13 1 14 15 16 17\n";
+        let count = |mode| count_safe_levels::<Heapless>(input.as_bytes(), mode).unwrap();
+        assert_eq!((count(Mode::Intolerant), count(Mode::Tolerant)), (0, 1));
+    }
+
+    #[test]
+    fn test_tolerance_too_big() {
+        let rules = Rules::with_tolerance(MAX_TOLERANCE + 1);
+        let input = b"1 2 3 4 5\n";
+        let err = count_safe_levels::<Heapless>(input, rules).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Capacity(CapacityError {
+                buffer: "dampener window",
+                capacity: MAX_TOLERANCE + 1,
+                line: 1,
+            })
+        );
+        assert_eq!(count_safe_levels::<Alloc>(input, rules), Ok(1));
+        assert_eq!(judge::<Heapless>(&[1, 2, 3, 4, 5], rules), None);
+    }
+
+    #[test]
+    fn test_step_bounds() {
+        let rules = Rules {
+            min_step: 2,
+            max_step: 5,
+            tolerance: 0,
+        };
+        let judge = |levels: &[u8]| judge::<Heapless>(levels, rules).unwrap();
+        assert_eq!(judge(&[1, 6, 8, 13]), Verdict::Safe);
+        assert_eq!(judge(&[1, 3, 4]), unsafe_at(2, Reason::TooSmall));
+        assert_eq!(judge(&[1, 7]), unsafe_at(1, Reason::TooBig));
+
+        // steps of nothing are fine, and go either way
+        let rules = Rules {
+            min_step: 0,
+            ..rules
+        };
+        assert_eq!(
+            super::judge::<Heapless>(&[5, 5, 3, 3], rules),
+            Some(Verdict::Safe)
+        );
+    }
+
+    /// Whether some way of taking out up to `tolerance` levels leaves a safe
+    /// report, trying every one.
+    fn brute_force(levels: &[u8], rules: &Rules) -> bool {
+        (0u32..1 << levels.len())
+            .filter(|removed| removed.count_ones() as usize <= rules.tolerance)
+            .any(|removed| {
+                let kept: std::vec::Vec<u8> = (0..levels.len())
+                    .filter(|index| removed & (1 << index) == 0)
+                    .map(|index| levels[index])
+                    .collect();
+                [true, false].into_iter().any(|rising| {
+                    kept.windows(2)
+                        .all(|pair| rules.step_ok(pair[0], pair[1], rising))
+                })
+            })
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let bounds = [(1, 3), (2, 4), (0, 1)];
+        let mut levels = std::vec::Vec::new();
+        for len in 1..=5 {
+            // every report of `len` levels from 0 to 5
+            for mut digits in 0..6usize.pow(len) {
+                levels.clear();
+                for _ in 0..len {
+                    levels.push((digits % 6) as u8);
+                    digits /= 6;
+                }
+                for (min_step, max_step) in bounds {
+                    for tolerance in 0..=2 {
+                        let rules = Rules {
+                            min_step,
+                            max_step,
+                            tolerance,
+                        };
+                        let mut checker = Checker::<Heapless>::new(rules);
+                        for &level in &levels {
+                            checker.push(level).unwrap();
+                        }
+                        assert_eq!(
+                            checker.is_safe(),
+                            brute_force(&levels, &rules),
+                            "{levels:?} with {rules:?}"
+                        );
+                    }
+                }
+
+                // the dampener owns up to the first level it could take out
+                let rules = Rules::with_tolerance(1);
+                if !brute_force(&levels, &Rules::with_tolerance(0)) {
+                    let first = (0..levels.len()).find(|&index| {
+                        let mut kept = levels.clone();
+                        kept.remove(index);
+                        brute_force(&kept, &Rules::with_tolerance(0))
+                    });
+                    let removed = match judge::<Heapless>(&levels, rules).unwrap() {
+                        Verdict::Dampened { removed } => Some(removed),
+                        _ => None,
+                    };
+                    assert_eq!(removed, first, "{levels:?}");
+                }
+            }
+        }
+    }
+}
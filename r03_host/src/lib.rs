@@ -0,0 +1,24 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use r03_lib::{part1, part2};
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = String;
+
+    // anything that isn't an instruction is skipped, so there is nothing
+    // here that can fail
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part1(input.as_bytes()).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part2(input.as_bytes()).into())
+    }
+}
@@ -0,0 +1,71 @@
+#![no_std]
+
+use aoc_core::tokens::Table;
+
+const MUL: &str = "mul({3},{3})";
+const DO: &str = "do()";
+const DONT: &str = "don't()";
+
+/// Part 1 only knows about `mul`s.
+static PART1: Table<13> = Table::compile(&[MUL]);
+/// Part 2 can be switched off and on again.
+static PART2: Table<22> = Table::compile(&[MUL, DO, DONT]);
+
+/// Adds up the `mul`s the table finds, only counting those after a `do()`
+/// or before any `don't()`. Everything else is corrupted memory.
+///
+/// An instruction can start inside one that broke off, so `mul(mul(2,3)`
+/// counts 6. The state machines this replaced, like c03 still does, went
+/// on from the byte after the one that broke the match and missed it.
+fn run<const S: usize>(table: &Table<S>, input: &[u8]) -> u32 {
+    let mut enabled = true;
+    let mut total = 0;
+
+    for token in table.tokens(input) {
+        match token.pattern {
+            DO => enabled = true,
+            DONT => enabled = false,
+            MUL if enabled => total += token.numbers[0] * token.numbers[1],
+            _ => {}
+        }
+    }
+
+    total
+}
+
+pub fn part1(input: &[u8]) -> u32 {
+    run(&PART1, input)
+}
+
+pub fn part2(input: &[u8]) -> u32 {
+    run(&PART2, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = b"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(part1(input), 161);
+        let input = b"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(part2(input), 48);
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(part1(b"mul(4*mul(6,9!?(12,34)mul ( 2 , 4 )"), 0);
+        assert_eq!(part1(b"mul(1234,5)mul(123,4)"), 492);
+        // a mul can start part way through a broken one
+        assert_eq!(part1(b"mmul(2,3)mul(mul(1,1)"), 7);
+    }
+
+    #[test]
+    fn test_restart() {
+        assert_eq!(part1(b"mul(mul(2,3)"), 6);
+        // and so can the switches
+        assert_eq!(part2(b"don't()mul(2,3)ddo()mul(4,5)"), 20);
+        assert_eq!(part2(b"don't(don't()mul(2,3)"), 0);
+    }
+}
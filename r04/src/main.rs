@@ -2,138 +2,54 @@
 #![no_main]
 
 use bsp::entry;
+use bsp::hal::{clocks::init_clocks_and_plls, pac, Timer, Watchdog};
 use defmt::*;
 use defmt_rtt as _;
 use panic_probe as _;
 use rp_pico as bsp;
 
-use core::hint::black_box;
-use core::str;
-use heapless::{String, Vec};
+use aoc_core::report::report_answers;
+use aoc_core::stack::{paint_stack, stack_high_water};
+use aoc_core::timing::{Stopwatch, Timings};
+use r04_lib::{part1, part2, read_map};
 
-const MAX_ROWS: usize = 140;
-const MAX_COLS: usize = 140;
-const MAX_WORD_LEN: usize = 4;
-type Map = Vec<Vec<char, MAX_COLS>, MAX_ROWS>;
-
-fn part1(input: &str, word: &str) -> u32 {
-    let mut map: Map = Vec::new();
-    let mut count = 0;
-    let rev_word: String<MAX_WORD_LEN> = word.chars().rev().collect();
-
-    for line in input.lines() {
-        let _ = map.push(line.chars().collect());
-    }
-
-    for row in 0..map.len() {
-        for col in 0..map.first().unwrap().len() {
-            let row_margin = row <= (map.len() - word.len());
-            let col_margin = col <= (map.first().unwrap().len() - word.len());
-
-            if col_margin {
-                if find_horizontal(&map, word, row, col)
-                    || find_horizontal(&map, &rev_word, row, col)
-                {
-                    count += 1;
-                }
-            }
-
-            if row_margin {
-                if find_vertical(&map, word, row, col) ||
-                    find_vertical(&map, &rev_word, row, col) {
-                    count += 1;
-                }
-            }
-
-            if row_margin && col_margin {
-                if find_pos_diag(&map, word, row, col) ||
-                    find_pos_diag(&map, &rev_word, row, col) {
-                    count += 1;
-                }
-                if find_neg_diag(&map, word, row, col) ||
-                    find_neg_diag(&map, &rev_word, row, col) {
-                    count += 1;
-                }
-            }
-        }
-    }
-
-    count
-}
-
-fn part2(input: &str, word: &str) -> u32 {
-    let mut map: Map = Vec::new();
-    let mut count = 0;
-    let rev_word: String<MAX_WORD_LEN> = word.chars().rev().collect();
-
-    for line in input.lines() {
-        let _ = map.push(line.chars().collect());
-    }
-
-    for row in 0..(map.len() - word.len() + 1) {
-        for col in 0..(map.first().unwrap().len() - word.len() + 1) {
-            let pos = find_pos_diag(&map, word, row, col);
-            let pos_rev = find_pos_diag(&map, &rev_word, row, col);
-            let neg = find_neg_diag(&map, word, row, col);
-            let neg_rev = find_neg_diag(&map, &rev_word, row, col);
-
-            if (pos || pos_rev) && (neg || neg_rev) {
-                count += 1;
-            }
-        }
-    }
-
-    count
-}
-
-fn find_horizontal(map: &Map, word: &str, row: usize, col: usize) -> bool {
-    word.chars()
-        .zip(map[row][col..].iter())
-        .all(|(a, b)| a == *b)
-}
-
-fn find_vertical(map: &Map, word: &str, row: usize, col: usize) -> bool {
-    map.iter()
-        .skip(row)
-        .take(word.len())
-        .filter_map(|row_| row_.get(col))
-        .zip(word.chars())
-        .all(|(a, b)| *a == b)
-}
-
-fn find_pos_diag(map: &Map, word: &str, row: usize, col: usize) -> bool {
-    map.iter()
-        .skip(row)
-        .take(word.len())
-        .enumerate()
-        .filter_map(|(idx, row_)| row_.get(col + word.len() - 1 - idx))
-        .zip(word.chars())
-        .all(|(a, b)| *a == b)
-}
-
-fn find_neg_diag(map: &Map, word: &str, row: usize, col: usize) -> bool {
-    map.iter()
-        .skip(row)
-        .take(word.len())
-        .enumerate()
-        .filter_map(|(idx, row_)| row_.get(col + idx))
-        .zip(word.chars())
-        .all(|(a, b)| *a == b)
-}
+include!(concat!(env!("OUT_DIR"), "/input.rs"));
 
 #[entry]
 fn main() -> ! {
     info!("Program start");
-
-    let inputs = include_str!("../input.txt");
-
-    let ans1 = part1(inputs, "XMAS");
-    let ans2 = part2(inputs, "MAS");
+    paint_stack();
+
+    let mut pac = pac::Peripherals::take().unwrap();
+    let mut watchdog = Watchdog::new(pac.WATCHDOG);
+    let clocks = init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+    let timer = Timer::new(pac.TIMER, &mut pac.RESETS, &clocks);
+    let clock = || timer.get_counter().ticks();
+
+    let mut stopwatch = Stopwatch::start(&clock);
+    let map = unwrap!(read_map(INPUT));
+    let parse_us = stopwatch.lap();
+    let ans1 = part1(&map, "XMAS");
+    let part1_us = stopwatch.lap();
+    let ans2 = part2(&map, "MAS");
+    let part2_us = stopwatch.lap();
     info!("calculation finished");
 
-    // forcing the compiler to keeps these alive so I can view them
-    // with the debugger
-    black_box(ans1);
-    black_box(ans2);
+    let timings = Timings {
+        parse_us,
+        part1_us,
+        part2_us,
+    };
+    report_answers(4, ans1, ans2, timings, stack_high_water());
     loop {}
 }
@@ -0,0 +1,22 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use r04_lib::{Map, part1, part2, read_map};
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Map<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(read_map(input.as_bytes().to_vec())?)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part1(map, "XMAS").into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part2(map, "MAS").into())
+    }
+}
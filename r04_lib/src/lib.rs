@@ -0,0 +1,90 @@
+#![no_std]
+
+use aoc_core::parse::ParseError;
+use aoc_grid::{Dir, GridView, Offset, Pos};
+
+const SOUTH_EAST: Offset = Offset::new(1, 1);
+const SOUTH_WEST: Offset = Offset::new(1, -1);
+
+/// The word search, read where it lies in the input.
+pub type Map<B> = GridView<B>;
+
+pub fn read_map<B: AsRef<[u8]>>(input: B) -> Result<Map<B>, ParseError> {
+    Map::parse(input, |byte| byte.is_ascii_uppercase(), "a letter")
+}
+
+/// Whether `word` is spelled out from `from` going `offset`, either way round.
+fn reads<B: AsRef<[u8]>>(map: &Map<B>, from: Pos, offset: Offset, word: &str) -> bool {
+    let letters = || {
+        map.ray(from, offset)
+            .map(|(_, letter)| letter)
+            .take(word.len())
+    };
+    letters().eq(word.bytes()) || letters().eq(word.bytes().rev())
+}
+
+pub fn part1<B: AsRef<[u8]>>(map: &Map<B>, word: &str) -> u32 {
+    // the other four directions are these read backwards
+    let offsets = [Dir::East.into(), Dir::South.into(), SOUTH_EAST, SOUTH_WEST];
+    let mut count = 0;
+
+    for pos in map.positions() {
+        for offset in offsets {
+            if reads(map, pos, offset, word) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub fn part2<B: AsRef<[u8]>>(map: &Map<B>, word: &str) -> u32 {
+    let mut count = 0;
+
+    for pos in map.positions() {
+        let top_right = Pos::new(pos.row, pos.col + word.len() - 1);
+        if reads(map, pos, SOUTH_EAST, word) && reads(map, top_right, SOUTH_WEST, word) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::parse::{Expected, Found};
+
+    #[test]
+    fn test_example() {
+        let input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+        let map = read_map(input.as_bytes()).unwrap();
+        assert_eq!((part1(&map, "XMAS"), part2(&map, "MAS")), (18, 9));
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let err = read_map(b"XMAS\nXMA\n").err().unwrap();
+        assert_eq!(
+            err,
+            ParseError {
+                line: 2,
+                column: 4,
+                expected: Expected::Thing("a letter"),
+                found: Found::EndOfLine,
+            }
+        );
+    }
+}
@@ -0,0 +1,25 @@
+MEMORY {
+    BOOT2 : ORIGIN = 0x10000000, LENGTH = 0x100
+    FLASH : ORIGIN = 0x10000100, LENGTH = 2048K - 0x100
+    RAM   : ORIGIN = 0x20000000, LENGTH = 256K
+    /* past the striped banks, so the result record never moves */
+    SRAM4 : ORIGIN = 0x20040000, LENGTH = 4K
+}
+
+EXTERN(BOOT2_FIRMWARE)
+
+SECTIONS {
+    /* ### Boot loader */
+    .boot2 ORIGIN(BOOT2) :
+    {
+        KEEP(*(.boot2));
+    } > BOOT2
+} INSERT BEFORE .text;
+
+SECTIONS {
+    /* ### aoc_core::report::AOC_RESULT */
+    .aoc_result (NOLOAD) :
+    {
+        KEEP(*(.aoc_result));
+    } > SRAM4
+} INSERT AFTER .uninit;
\ No newline at end of file
@@ -2,103 +2,55 @@
 #![no_main]
 
 use bsp::entry;
+use bsp::hal::{clocks::init_clocks_and_plls, pac, Timer, Watchdog};
 use defmt::*;
 use defmt_rtt as _;
 use panic_probe as _;
 use rp_pico as bsp;
 
-use core::hint::black_box;
-use core::str;
-use heapless::Vec;
+use aoc_core::report::report_answers;
+use aoc_core::stack::{paint_stack, stack_high_water};
+use aoc_core::storage::Heapless;
+use aoc_core::timing::{Stopwatch, Timings};
+use r05_lib::{check_updates, Mode};
 
-const MAX_PAGES: usize = 100;
-const MAX_DEPS: usize = 100;
-const MAX_UPDATES: usize = 30;
-
-type DepList = Vec<Vec<u8, MAX_DEPS>, MAX_PAGES>;
-
-#[derive(PartialEq)]
-enum Mode {
-    FixUpdates,
-    DontFix,
-}
-
-fn check_updates(input: &str, mode: Mode) -> u32 {
-    let mut deplist: DepList = (0..MAX_PAGES).map(|_| Vec::new()).collect();
-    let mut update: Vec<u8, MAX_UPDATES> = Vec::new();
-    let mut first_section = true;
-    let mut total = 0;
-    let mut correct_order = true;
-
-    for line in input.lines() {
-        if line.trim().is_empty() {
-            first_section = false;
-            continue;
-        }
-
-        if first_section {
-            let mut split = line.splitn(2, '|');
-            let dep: u8 = split.next().unwrap().parse().unwrap();
-            let page: usize = split.next().unwrap().parse().unwrap();
-            let _ = deplist[page].push(dep);
-        } else {
-            for page_str in line.split(',') {
-                let page: u8 = page_str.parse().unwrap();
-
-                for prev_page in update.iter() {
-                    if deplist[*prev_page as usize].contains(&page) {
-                        correct_order = false;
-                    }
-                }
-
-                let _ = update.push(page);
-            }
-
-            if (mode == Mode::DontFix) && correct_order {
-                total += update[update.len() / 2] as u32;
-            }
-
-            if (mode == Mode::FixUpdates) && !correct_order {
-                fix(&mut update, &deplist);
-                total += update[update.len() / 2] as u32;
-            }
-
-            update.clear();
-            correct_order = true;
-        }
-    }
-
-    total
-}
-
-fn fix(update: &mut Vec<u8, MAX_UPDATES>, deplist: &DepList) {
-    'outer: loop {
-        for idx in 1..update.len() {
-            for prev_idx in 0..idx {
-                if deplist[update[prev_idx] as usize].contains(&update[idx]) {
-                    update.swap(idx, prev_idx);
-                    continue 'outer;
-                }
-            }
-        }
-
-        break;
-    }
-}
+include!(concat!(env!("OUT_DIR"), "/input.rs"));
 
 #[entry]
 fn main() -> ! {
     info!("Program start");
-
-    let inputs = include_str!("../input.txt");
-
-    let ans1 = check_updates(inputs, Mode::DontFix);
-    let ans2 = check_updates(inputs, Mode::FixUpdates);
+    paint_stack();
+
+    let mut pac = pac::Peripherals::take().unwrap();
+    let mut watchdog = Watchdog::new(pac.WATCHDOG);
+    let clocks = init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+    let timer = Timer::new(pac.TIMER, &mut pac.RESETS, &clocks);
+    let clock = || timer.get_counter().ticks();
+
+    let mut stopwatch = Stopwatch::start(&clock);
+    // check_updates parses the rules and updates as it goes
+    let parse_us = 0;
+    let ans1 = unwrap!(check_updates::<Heapless>(INPUT, Mode::DontFix));
+    let part1_us = stopwatch.lap();
+    let ans2 = unwrap!(check_updates::<Heapless>(INPUT, Mode::FixUpdates));
+    let part2_us = stopwatch.lap();
     info!("calculation finished");
 
-    // forcing the compiler to keeps these alive so I can view them
-    // with the debugger
-    black_box(ans1);
-    black_box(ans2);
+    let timings = Timings {
+        parse_us,
+        part1_us,
+        part2_us,
+    };
+    report_answers(5, ans1, ans2, timings, stack_high_water());
     loop {}
 }
@@ -0,0 +1,25 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::storage::Alloc;
+use r05_lib::{Mode, check_updates};
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = String;
+
+    // check_updates reads the updates as it goes, so a bad one turns up in
+    // whichever part gets to it first
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(check_updates::<Alloc>(input.as_bytes(), Mode::DontFix)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(check_updates::<Alloc>(input.as_bytes(), Mode::FixUpdates)?.into())
+    }
+}
@@ -0,0 +1,156 @@
+#![no_std]
+
+use aoc_core::error::{CapacityError, InputError, OrFull};
+use aoc_core::scan::Scanner;
+use aoc_core::storage::{List, Storage};
+
+pub const MAX_PAGES: usize = 100;
+pub const MAX_UPDATES: usize = 30;
+
+/// The pages each page has to come after, a bit per page.
+pub type DepList = [u128; MAX_PAGES];
+
+#[derive(PartialEq)]
+pub enum Mode {
+    FixUpdates,
+    DontFix,
+}
+
+/// A page number that fits the page table.
+fn page(scan: &mut Scanner) -> Result<u8, InputError> {
+    let line = scan.line();
+    let page: u8 = scan.number()?;
+    if page as usize >= MAX_PAGES {
+        return Err(CapacityError {
+            buffer: "page table",
+            capacity: MAX_PAGES,
+            line,
+        }
+        .into());
+    }
+    Ok(page)
+}
+
+fn depends(deplist: &DepList, page: u8, on: u8) -> bool {
+    deplist[page as usize] & (1 << on) != 0
+}
+
+pub fn check_updates<S: Storage>(input: &[u8], mode: Mode) -> Result<u32, InputError> {
+    let mut deplist: DepList = [0; MAX_PAGES];
+    let mut update: S::Vec<u8, MAX_UPDATES> = Default::default();
+    let mut total = 0;
+    let mut correct_order = true;
+    let mut scan = Scanner::new(input);
+
+    while !scan.end_section() {
+        let dep = page(&mut scan)?;
+        scan.tag("|")?;
+        let page = page(&mut scan)?;
+        scan.end_line()?;
+        deplist[page as usize] |= 1 << dep;
+    }
+
+    while !scan.is_empty() {
+        let line = scan.line();
+        loop {
+            let page = page(&mut scan)?;
+            if update
+                .iter()
+                .any(|&prev_page| depends(&deplist, prev_page, page))
+            {
+                correct_order = false;
+            }
+
+            update.push(page).or_full("update", MAX_UPDATES, line)?;
+            if scan.at_line_end() {
+                break;
+            }
+            scan.tag(",")?;
+        }
+        scan.end_line()?;
+
+        if (mode == Mode::DontFix) && correct_order {
+            total += update[update.len() / 2] as u32;
+        }
+
+        if (mode == Mode::FixUpdates) && !correct_order {
+            fix(&mut update, &deplist);
+            total += update[update.len() / 2] as u32;
+        }
+
+        update.clear();
+        correct_order = true;
+    }
+
+    Ok(total)
+}
+
+fn fix(update: &mut [u8], deplist: &DepList) {
+    'outer: loop {
+        for idx in 1..update.len() {
+            for prev_idx in 0..idx {
+                if depends(deplist, update[prev_idx], update[idx]) {
+                    update.swap(idx, prev_idx);
+                    continue 'outer;
+                }
+            }
+        }
+
+        break;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use aoc_core::parse::{Expected, Found, ParseError};
+    use aoc_core::storage::Heapless;
+    use std::string::String;
+
+    #[test]
+    fn test_page_out_of_range() {
+        let input = "47|53\n97|100\n\n75,47,61\n";
+        let err = check_updates::<Heapless>(input.as_bytes(), Mode::DontFix).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Capacity(CapacityError {
+                buffer: "page table",
+                capacity: MAX_PAGES,
+                line: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_update_too_long() {
+        let pages: std::vec::Vec<String> = (10..10 + MAX_UPDATES + 1)
+            .map(|page| std::format!("{page}"))
+            .collect();
+        let input = std::format!("47|53\n\n{}\n", pages.join(","));
+        let err = check_updates::<Heapless>(input.as_bytes(), Mode::FixUpdates).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Capacity(CapacityError {
+                buffer: "update",
+                capacity: MAX_UPDATES,
+                line: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_blank_line() {
+        let err = check_updates::<Heapless>(b"47|53\n75,47,61\n", Mode::DontFix).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Parse(ParseError {
+                line: 2,
+                column: 3,
+                expected: Expected::Text("|"),
+                found: Found::Char(','),
+            })
+        );
+    }
+}
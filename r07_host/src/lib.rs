@@ -0,0 +1,25 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::storage::Alloc;
+use r07_lib::{Mode, calibrate};
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = String;
+
+    // calibrate reads the equations as it goes, so a bad one turns up in
+    // whichever part gets to it first
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(calibrate::<Alloc>(input.as_bytes(), Mode::AddMul)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(calibrate::<Alloc>(input.as_bytes(), Mode::Concat)?.into())
+    }
+}
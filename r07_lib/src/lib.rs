@@ -0,0 +1,137 @@
+#![no_std]
+
+use aoc_core::error::{InputError, OrFull};
+use aoc_core::parse::Expected;
+use aoc_core::scan::Scanner;
+use aoc_core::storage::{List, Storage};
+
+pub const MAX_TERMS: usize = 30;
+
+/// The puzzle's terms have at most three digits, and the targets are what
+/// get big.
+pub type Term = u16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Only `+` and `*`.
+    AddMul,
+    /// `||` as well.
+    Concat,
+}
+
+fn conc(pre: u64, post: u64) -> u64 {
+    for expo in 1..20 {
+        if post < 10_u64.pow(expo) {
+            return pre * 10u64.pow(expo) + post;
+        }
+    }
+
+    0
+}
+
+fn can_calc(terms: &[Term], current: u64, target: u64, concat: bool) -> bool {
+    if current > target {
+        return false;
+    }
+
+    if let Some((&next_term, terms)) = terms.split_first() {
+        let next_term = u64::from(next_term);
+        let add_next = can_calc(terms, current + next_term, target, concat);
+        let mul_next = can_calc(terms, current * next_term, target, concat);
+
+        if concat {
+            let concat_next = can_calc(terms, conc(current, next_term), target, concat);
+            return add_next || mul_next || concat_next;
+        } else {
+            return add_next || mul_next;
+        }
+    }
+
+    target == current
+}
+
+/// The total of the targets the terms can make with the operators `mode`
+/// allows.
+pub fn calibrate<S: Storage>(input: &[u8], mode: Mode) -> Result<u64, InputError> {
+    let mut total = 0u64;
+    let mut scan = Scanner::new(input);
+
+    while !scan.is_empty() {
+        let line = scan.line();
+        let target: u64 = scan.number()?;
+        scan.tag(":")?;
+        let mut terms: S::Vec<Term, MAX_TERMS> = Default::default();
+        while !scan.at_line_end() {
+            scan.tag(" ")?;
+            terms
+                .push(scan.number()?)
+                .or_full("terms", MAX_TERMS, line)?;
+        }
+
+        let Some((&first_term, terms)) = terms.split_first() else {
+            return Err(scan.error(Expected::Text(" ")).into());
+        };
+        let first_term = u64::from(first_term);
+        scan.end_line()?;
+        if can_calc(terms, first_term, target, mode == Mode::Concat) {
+            total += target;
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use aoc_core::error::CapacityError;
+    use aoc_core::parse::{Found, ParseError};
+    use aoc_core::storage::Heapless;
+    use std::string::String;
+
+    #[test]
+    fn test_too_many_terms() {
+        let mut input: String = "190: 10 19\n".into();
+        input.push_str("1:");
+        input.extend((0..MAX_TERMS + 1).map(|_| " 1"));
+        let err = calibrate::<Heapless>(input.as_bytes(), Mode::AddMul).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Capacity(CapacityError {
+                buffer: "terms",
+                capacity: MAX_TERMS,
+                line: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_terms() {
+        let err = calibrate::<Heapless>(b"190: 10 19\n3267:\n", Mode::AddMul).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Parse(ParseError {
+                line: 2,
+                column: 6,
+                expected: Expected::Text(" "),
+                found: Found::EndOfLine,
+            })
+        );
+    }
+
+    #[test]
+    fn test_term_too_big() {
+        let err = calibrate::<Heapless>(b"65536: 65536\n", Mode::AddMul).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Parse(ParseError {
+                line: 1,
+                column: 8,
+                expected: Expected::Thing("a number that fits"),
+                found: Found::Char('6'),
+            })
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::storage::Alloc;
+use r08_lib::Antennas;
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = Antennas<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Antennas::new(input.as_bytes().to_vec())
+    }
+
+    fn part1(&self, antennas: &Self::Input) -> Result<Answer, InputError> {
+        Ok(antennas.part1::<Alloc>()?.into())
+    }
+
+    fn part2(&self, antennas: &Self::Input) -> Result<Answer, InputError> {
+        Ok(antennas.part2::<Alloc>()?.into())
+    }
+}
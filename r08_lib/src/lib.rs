@@ -0,0 +1,230 @@
+#![no_std]
+
+use aoc_core::error::{CapacityError, InputError, OrFull};
+use aoc_core::storage::{List, Storage};
+use aoc_grid::GridView;
+
+pub const MAX_NODES: usize = 1000;
+/// Positions are kept as `i8`s.
+pub const MAX_SIZE: usize = i8::MAX as usize + 1;
+
+fn gcd(mut a: i16, mut b: i16) -> i16 {
+    // with thanks to ChatGPT / Euclid
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+/// The position `row`, `col`, if it's on the map. Positions fit an `i8`,
+/// but one step on from an antenna can be a whole map width past it, so
+/// the sums are done in `i16`.
+fn on_map(row: i16, col: i16, maxrow: i8, maxcol: i8) -> Option<(i8, i8)> {
+    let on = (0..=i16::from(maxrow)).contains(&row) && (0..=i16::from(maxcol)).contains(&col);
+    on.then_some((row as i8, col as i8))
+}
+
+/// The row and column steps from `earlier` to `later`.
+fn delta(later: (i8, i8, u8), earlier: (i8, i8, u8)) -> (i16, i16) {
+    (
+        i16::from(later.0) - i16::from(earlier.0),
+        i16::from(later.1) - i16::from(earlier.1),
+    )
+}
+
+fn part1<S: Storage>(
+    nodes: impl Iterator<Item = (i8, i8, u8)> + Clone,
+    maxrow: i8,
+    maxcol: i8,
+) -> Result<usize, CapacityError> {
+    let mut antinodes: S::Vec<(i8, i8), MAX_NODES> = Default::default();
+
+    for (later_idx, later_node) in nodes.clone().enumerate() {
+        for earlier_node in nodes.clone().take(later_idx) {
+            if later_node.2 != earlier_node.2 {
+                continue;
+            }
+            let line_no = later_node.0 as usize + 1;
+
+            let (delta_row, delta_col) = delta(later_node, earlier_node);
+            let beyond = [
+                (
+                    i16::from(earlier_node.0) - delta_row,
+                    i16::from(earlier_node.1) - delta_col,
+                ),
+                (
+                    i16::from(later_node.0) + delta_row,
+                    i16::from(later_node.1) + delta_col,
+                ),
+            ];
+            for (row, col) in beyond {
+                if let Some(antinode) = on_map(row, col, maxrow, maxcol)
+                    && !antinodes.contains(&antinode)
+                {
+                    antinodes
+                        .push(antinode)
+                        .or_full("antinodes", MAX_NODES, line_no)?;
+                }
+            }
+        }
+    }
+
+    Ok(antinodes.len())
+}
+
+fn part2<S: Storage>(
+    nodes: impl Iterator<Item = (i8, i8, u8)> + Clone,
+    maxrow: i8,
+    maxcol: i8,
+) -> Result<usize, CapacityError> {
+    let mut antinodes: S::Vec<(i8, i8), MAX_NODES> = Default::default();
+
+    for (later_idx, later_node) in nodes.clone().enumerate() {
+        for earlier_node in nodes.clone().take(later_idx) {
+            if later_node.2 != earlier_node.2 {
+                continue;
+            }
+            let line_no = later_node.0 as usize + 1;
+
+            let (mut delta_row, mut delta_col) = delta(later_node, earlier_node);
+            let delta_gcd = gcd(delta_row, delta_col);
+            delta_row /= delta_gcd;
+            delta_col /= delta_gcd;
+
+            // back from the earlier antenna, then on from the later one
+            for (from, step) in [(earlier_node, -1), (later_node, 1)] {
+                let (row, col) = (i16::from(from.0), i16::from(from.1));
+                for multiple in 0.. {
+                    let Some(antinode) = on_map(
+                        row + step * multiple * delta_row,
+                        col + step * multiple * delta_col,
+                        maxrow,
+                        maxcol,
+                    ) else {
+                        break;
+                    };
+                    if !antinodes.contains(&antinode) {
+                        antinodes
+                            .push(antinode)
+                            .or_full("antinodes", MAX_NODES, line_no)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(antinodes.len())
+}
+
+/// The antenna map, read where it lies in the input. The antennas are
+/// picked out of it again whenever they are wanted rather than kept.
+pub struct Antennas<B> {
+    map: GridView<B>,
+}
+
+impl<B: AsRef<[u8]>> Antennas<B> {
+    pub fn new(input: B) -> Result<Self, InputError> {
+        let valid = |byte: u8| byte == b'.' || byte.is_ascii_alphanumeric();
+        let map = GridView::parse(input, valid, "an antenna or '.'")?;
+
+        // a row that is too long is too long on the first line
+        let too_big = if map.height() > MAX_SIZE {
+            Some(("map", MAX_SIZE + 1))
+        } else if map.width() > MAX_SIZE {
+            Some(("map row", 1))
+        } else {
+            None
+        };
+        if let Some((buffer, line)) = too_big {
+            return Err(CapacityError {
+                buffer,
+                capacity: MAX_SIZE,
+                line,
+            }
+            .into());
+        }
+
+        Ok(Self { map })
+    }
+
+    /// Every antenna as its row, column and frequency, row by row.
+    fn nodes(&self) -> impl Iterator<Item = (i8, i8, u8)> + Clone + '_ {
+        self.map
+            .iter()
+            .filter(|&(_, cell)| cell != b'.')
+            .map(|(pos, cell)| (pos.row as i8, pos.col as i8, cell))
+    }
+
+    fn max_pos(&self) -> (i8, i8) {
+        ((self.map.height() - 1) as i8, (self.map.width() - 1) as i8)
+    }
+
+    pub fn part1<S: Storage>(&self) -> Result<usize, CapacityError> {
+        let (maxrow, maxcol) = self.max_pos();
+        part1::<S>(self.nodes(), maxrow, maxcol)
+    }
+
+    pub fn part2<S: Storage>(&self) -> Result<usize, CapacityError> {
+        let (maxrow, maxcol) = self.max_pos();
+        part2::<S>(self.nodes(), maxrow, maxcol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use aoc_core::parse::{Expected, Found, ParseError};
+    use aoc_core::storage::Heapless;
+    use std::string::String;
+    use std::vec;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_map_too_big() {
+        let row: String = (0..MAX_SIZE + 1).map(|_| '.').chain(Some('\n')).collect();
+        let err = Antennas::new(row.repeat(2).as_bytes()).err().unwrap();
+        assert_eq!(
+            err,
+            InputError::Capacity(CapacityError {
+                buffer: "map row",
+                capacity: MAX_SIZE,
+                line: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_opposite_corners() {
+        // the antinodes are most of a map past either corner, off both ends
+        // of an i8, and the pairs are too far apart to have any in between
+        let mut rows: Vec<Vec<u8>> = (0..MAX_SIZE).map(|_| vec![b'.'; MAX_SIZE]).collect();
+        rows[0][0] = b'a';
+        rows[MAX_SIZE - 1][MAX_SIZE - 2] = b'a';
+        rows[0][MAX_SIZE - 1] = b'b';
+        rows[MAX_SIZE - 1][1] = b'b';
+        let input: Vec<u8> = rows.join(&b'\n').into_iter().chain(Some(b'\n')).collect();
+
+        let antennas = Antennas::new(input.as_slice()).unwrap();
+        assert_eq!(antennas.part1::<Heapless>(), Ok(0));
+        // only the antennas themselves
+        assert_eq!(antennas.part2::<Heapless>(), Ok(4));
+    }
+
+    #[test]
+    fn test_bad_antenna() {
+        let err = Antennas::new(b"..a.\n.#..\n").err().unwrap();
+        assert_eq!(
+            err,
+            InputError::Parse(ParseError {
+                line: 2,
+                column: 2,
+                expected: Expected::Thing("an antenna or '.'"),
+                found: Found::Char('#'),
+            })
+        );
+    }
+}
@@ -1,7 +1,11 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::{Expected, Lines, ParseError};
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone)]
-struct Segment {
+pub struct Segment {
     id: Option<u64>,
     len: u8,
 }
@@ -22,14 +26,19 @@ fn checksum(segs: &[Segment]) -> u64 {
     checksum
 }
 
-fn process_inputs(input: &str) -> Vec<Segment> {
+fn process_inputs(input: &str) -> Result<Vec<Segment>, ParseError> {
     let mut file = true;
     let mut segs = Vec::new();
     let mut id_no = 0;
 
-    for chr in input.lines().next().unwrap().chars() {
+    let mut line = Lines::new(input).expect("the disk map")?;
+    if line.is_empty() {
+        return Err(line.error(Expected::Thing("a length")));
+    }
+    while !line.is_empty() {
+        let chr = line.char(|chr| chr.is_ascii_digit(), "a length")?;
         let id = if file { Some(id_no) } else { None };
-        let len = chr as u8 - 48;
+        let len = chr as u8 - b'0';
         if len > 0 {
             segs.push(Segment { id, len });
         }
@@ -40,12 +49,13 @@ fn process_inputs(input: &str) -> Vec<Segment> {
         file = !file;
     }
 
-    segs
+    Ok(segs)
 }
 
 fn part1(mut segs: Vec<Segment>) -> u64 {
     let mut front_idx = 0usize;
-    let mut back_idx = segs.len() - 1;
+    // a disk map of nothing but zeros has no blocks to move
+    let mut back_idx = segs.len().saturating_sub(1);
 
     while front_idx < back_idx {
         if segs[front_idx].id.is_some() {
@@ -81,7 +91,7 @@ fn part1(mut segs: Vec<Segment>) -> u64 {
 }
 
 fn part2(mut segs: Vec<Segment>) -> u64 {
-    let mut back_idx = segs.len() - 1;
+    let mut back_idx = segs.len().saturating_sub(1);
 
     while back_idx > 0 {
         if segs[back_idx].id.is_none() {
@@ -120,10 +130,46 @@ fn part2(mut segs: Vec<Segment>) -> u64 {
     checksum(&segs)
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let segments = process_inputs(input);
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = Vec<Segment>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(process_inputs(input)?)
+    }
+
+    fn part1(&self, segments: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part1(segments.clone()).into())
+    }
+
+    fn part2(&self, segments: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part2(segments.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::parse::Found;
+
+    #[test]
+    fn test_blank_line() {
+        assert_eq!(
+            Day09.parse("\n").err(),
+            Some(InputError::Parse(ParseError {
+                line: 1,
+                column: 1,
+                expected: Expected::Thing("a length"),
+                found: Found::EndOfLine,
+            }))
+        );
+    }
 
-    dbg!(part1(segments.clone()));
-    dbg!(part2(segments));
+    #[test]
+    fn test_no_blocks() {
+        let segments = Day09.parse("000\n").unwrap();
+        assert_eq!(Day09.part1(&segments), Ok(0u64.into()));
+        assert_eq!(Day09.part2(&segments), Ok(0u64.into()));
+    }
 }
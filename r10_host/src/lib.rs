@@ -0,0 +1,66 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::storage::Alloc;
+use aoc_grid::{GridView, Pos};
+use aoc_search::{Dag, SearchProblem, all_shortest_paths};
+use r10_lib::Map;
+
+/// Walking uphill a step at a time from one trail head to any summit.
+struct Trail<'a> {
+    heights: &'a GridView<Vec<u8>>,
+    head: Pos,
+}
+
+impl SearchProblem for Trail<'_> {
+    type State = Pos;
+
+    fn start(&self) -> Pos {
+        self.head
+    }
+
+    fn successors(&self, &pos: &Pos) -> impl IntoIterator<Item = (Pos, usize)> {
+        let uphill = self.heights[pos] + 1;
+        self.heights
+            .neighbours(pos)
+            .filter(move |&next| self.heights[next] == uphill)
+            .map(|next| (next, 1))
+    }
+
+    fn is_goal(&self, &pos: &Pos) -> bool {
+        self.heights[pos] == b'9'
+    }
+}
+
+/// Trails only ever climb, so every trail to a summit is a shortest path to it.
+fn trails(map: &Map<Alloc, Vec<u8>>) -> impl Iterator<Item = Dag<Pos>> {
+    let heights = map.heights();
+    heights
+        .iter()
+        .filter(|&(_, height)| height == b'0')
+        .filter_map(|(head, _)| all_shortest_paths(&Trail { heights, head }))
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Map<Alloc, Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Map::new(input.as_bytes().to_vec())
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Answer, InputError> {
+        Ok(trails(map)
+            .map(|dag| dag.goals().len())
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Answer, InputError> {
+        Ok(trails(map)
+            .map(|dag| dag.count_paths())
+            .sum::<usize>()
+            .into())
+    }
+}
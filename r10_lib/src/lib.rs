@@ -0,0 +1,154 @@
+#![no_std]
+
+use aoc_core::error::{CapacityError, InputError, OrFull};
+use aoc_core::storage::{List, Queue, Storage};
+use aoc_grid::{GridView, Pos};
+use core::marker::PhantomData;
+
+pub const MAX_TASKS: usize = 2000;
+pub const MAX_DEST: usize = 500;
+
+/// The heights are left as the digits in the input, which climb just like
+/// the numbers do.
+pub struct Map<S: Storage, B> {
+    heights: GridView<B>,
+    storage: PhantomData<S>,
+}
+
+impl<S: Storage, B: AsRef<[u8]>> Map<S, B> {
+    pub fn new(input: B) -> Result<Self, InputError> {
+        let heights = GridView::parse(input, |byte| byte.is_ascii_digit(), "a height")?;
+
+        Ok(Self {
+            heights,
+            storage: PhantomData,
+        })
+    }
+
+    pub fn heights(&self) -> &GridView<B> {
+        &self.heights
+    }
+
+    fn next_steps(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let value = self.heights[pos];
+        self.heights
+            .neighbours(pos)
+            .filter(move |&next| self.heights[next] == value + 1)
+    }
+
+    fn trail_head_value1(&self, head: Pos) -> Result<usize, CapacityError> {
+        let line = head.row + 1;
+        let mut tasks: S::Deque<Pos, MAX_TASKS> = Default::default();
+        tasks.push_back(head).or_full("tasks", MAX_TASKS, line)?;
+
+        let mut destinations: S::Vec<Pos, MAX_DEST> = Default::default();
+        while let Some(task) = tasks.pop_front() {
+            if self.heights[task] == b'9' {
+                if !destinations.contains(&task) {
+                    destinations
+                        .push(task)
+                        .or_full("destinations", MAX_DEST, line)?;
+                }
+            } else {
+                for new_task in self.next_steps(task) {
+                    tasks
+                        .push_back(new_task)
+                        .or_full("tasks", MAX_TASKS, line)?;
+                }
+            }
+        }
+
+        Ok(destinations.len())
+    }
+
+    fn trail_head_value2(&self, head: Pos) -> Result<usize, CapacityError> {
+        let line = head.row + 1;
+        let mut tasks: S::Deque<Pos, MAX_TASKS> = Default::default();
+        tasks.push_back(head).or_full("tasks", MAX_TASKS, line)?;
+
+        let mut total = 0;
+        while let Some(task) = tasks.pop_front() {
+            if self.heights[task] == b'9' {
+                total += 1;
+            } else {
+                for new_task in self.next_steps(task) {
+                    tasks
+                        .push_back(new_task)
+                        .or_full("tasks", MAX_TASKS, line)?;
+                }
+            }
+        }
+
+        Ok(total)
+    }
+
+    fn sum_trail_heads(
+        &self,
+        trail_head_value: impl Fn(&Self, Pos) -> Result<usize, CapacityError>,
+    ) -> Result<usize, CapacityError> {
+        let mut total = 0;
+
+        for (pos, height) in self.heights.iter() {
+            if height == b'0' {
+                total += trail_head_value(self, pos)?;
+            }
+        }
+
+        Ok(total)
+    }
+
+    pub fn part1(&self) -> Result<usize, CapacityError> {
+        self.sum_trail_heads(Self::trail_head_value1)
+    }
+
+    pub fn part2(&self) -> Result<usize, CapacityError> {
+        self.sum_trail_heads(Self::trail_head_value2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use aoc_core::parse::{Expected, Found, ParseError};
+    use aoc_core::storage::{Alloc, Heapless};
+
+    const EXAMPLE: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn test_example_heapless() {
+        let map: Map<Heapless, _> = Map::new(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!((map.part1(), map.part2()), (Ok(36), Ok(81)));
+    }
+
+    #[test]
+    fn test_example_alloc() {
+        let map: Map<Alloc, _> = Map::new(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!((map.part1(), map.part2()), (Ok(36), Ok(81)));
+    }
+
+    #[test]
+    fn test_truncated_map() {
+        let err = Map::<Heapless, _>::new(&EXAMPLE.as_bytes()[..EXAMPLE.len() - 4])
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            InputError::Parse(ParseError {
+                line: 8,
+                column: 6,
+                expected: Expected::Thing("a height"),
+                found: Found::EndOfLine,
+            })
+        );
+    }
+}
@@ -2,83 +2,74 @@
 #![no_main]
 
 use bsp::entry;
+use bsp::hal::{clocks::init_clocks_and_plls, pac, Timer, Watchdog};
 use defmt::*;
 use defmt_rtt as _;
 use panic_probe as _;
 use rp_pico as bsp;
 
-use core::hint::black_box;
-use core::str;
+use aoc_core::report::report_answers;
+use aoc_core::stack::{paint_stack, stack_high_water};
+use aoc_core::storage::Memo;
+use aoc_core::timing::{Stopwatch, Timings};
 use fchashmap::FcHashMap;
-use heapless::Vec;
+use r11_lib::{blink_stones, Key};
+
+include!(concat!(env!("OUT_DIR"), "/input.rs"));
 
 const MAXMEMO: usize = 5000;
 
-fn stoney_gaze(num: u64, blinks_left: u8, memo: &mut FcHashMap<(u64, u8), u64, MAXMEMO>) -> u64 {
-    if blinks_left == 0 {
-        return 1;
-    }
+struct FcMemo(FcHashMap<Key, u64, MAXMEMO>);
 
-    if let Some(result) = memo.get(&(num, blinks_left)) {
-        return *result;
+impl Memo<Key, u64> for FcMemo {
+    fn capacity(&self) -> usize {
+        MAXMEMO
     }
 
-    let result = if num == 0 {
-        stoney_gaze(1, blinks_left - 1, memo)
-    } else if let Some((front, back)) = split_digits(num) {
-        stoney_gaze(front, blinks_left - 1, memo) + stoney_gaze(back, blinks_left - 1, memo)
-    } else {
-        stoney_gaze(num * 2024, blinks_left - 1, memo)
-    };
-
-    let _ = memo.insert((num, blinks_left), result);
-
-    return result;
-}
-
-fn split_digits(num: u64) -> Option<(u64, u64)> {
-    let mut digits: u32 = 0;
-
-    for idx in 1..30u32 {
-        if num < 10u64.pow(idx) {
-            digits = idx;
-            break;
-        }
+    fn get(&self, key: &Key) -> Option<&u64> {
+        self.0.get(key)
     }
 
-    if digits % 2 == 0 {
-        let front = num / 10u64.pow(digits / 2);
-        let back = num - (front * 10u64.pow(digits / 2));
-        return Some((front, back));
+    fn insert(&mut self, key: Key, value: u64) -> Result<(), (Key, u64)> {
+        self.0.insert(key, value).map(|_| ())
     }
-
-    None
-}
-
-fn blink_stones(input: &str, blinks: u8) -> u64 {
-    let mut memo = FcHashMap::new();
-
-    input
-        .lines()
-        .next()
-        .unwrap()
-        .split(' ')
-        .map(|n| stoney_gaze(n.parse().unwrap(), blinks, &mut memo))
-        .sum()
 }
 
 #[entry]
 fn main() -> ! {
     info!("Program start");
-
-    let inputs = include_str!("../example1.txt");
-    let ans1 = blink_stones(inputs, 25);
-    let ans2 = blink_stones(inputs, 75);
+    paint_stack();
+
+    let mut pac = pac::Peripherals::take().unwrap();
+    let mut watchdog = Watchdog::new(pac.WATCHDOG);
+    let clocks = init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+    let timer = Timer::new(pac.TIMER, &mut pac.RESETS, &clocks);
+    let clock = || timer.get_counter().ticks();
+
+    let mut stopwatch = Stopwatch::start(&clock);
+    // the stones get parsed as they are blinked at
+    let parse_us = 0;
+    let ans1 = unwrap!(blink_stones(INPUT, 25, &mut FcMemo(FcHashMap::new())));
+    let part1_us = stopwatch.lap();
+    let ans2 = unwrap!(blink_stones(INPUT, 75, &mut FcMemo(FcHashMap::new())));
+    let part2_us = stopwatch.lap();
     info!("calculation finished");
 
-    // forcing the compiler to keeps these alive so I can view them
-    // with the debugger
-    black_box(ans1);
-    black_box(ans2);
+    let timings = Timings {
+        parse_us,
+        part1_us,
+        part2_us,
+    };
+    report_answers(11, ans1, ans2, timings, stack_high_water());
     loop {}
 }
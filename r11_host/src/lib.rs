@@ -0,0 +1,25 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use r11_lib::blink_stones;
+use std::collections::HashMap;
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        // no blinks just counts the stones, which is enough to check them
+        blink_stones(input.as_bytes(), 0, &mut HashMap::new())?;
+        Ok(input.as_bytes().to_vec())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(blink_stones(input, 25, &mut HashMap::new())?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, InputError> {
+        Ok(blink_stones(input, 75, &mut HashMap::new())?.into())
+    }
+}
@@ -0,0 +1,160 @@
+#![no_std]
+
+use aoc_core::error::{CapacityError, InputError, OrFull};
+use aoc_core::parse::Expected;
+use aoc_core::scan::Scanner;
+use aoc_core::storage::Memo;
+
+/// A stone and the blinks it has left, packed into one word so a memo
+/// entry takes 8 bytes less than it would keyed by `(u64, u8)`.
+pub type Key = u64;
+
+/// The top bits of a `Key` hold the blinks, which the puzzle never has more
+/// than 75 of.
+const BLINK_BITS: u32 = 7;
+
+/// The key for `num` with `blinks_left`, unless one of them is too big to
+/// share the word, in which case that stone isn't memoised.
+fn key(num: u64, blinks_left: u8) -> Option<Key> {
+    let shift = u64::BITS - BLINK_BITS;
+    (num >> shift == 0 && u32::from(blinks_left) >> BLINK_BITS == 0)
+        .then(|| u64::from(blinks_left) << shift | num)
+}
+
+pub fn stoney_gaze<M: Memo<Key, u64>>(
+    num: u64,
+    blinks_left: u8,
+    memo: &mut M,
+) -> Result<u64, CapacityError> {
+    if blinks_left == 0 {
+        return Ok(1);
+    }
+
+    let key = key(num, blinks_left);
+    if let Some(result) = key.and_then(|key| memo.get(&key)) {
+        return Ok(*result);
+    }
+
+    let result = if num == 0 {
+        stoney_gaze(1, blinks_left - 1, memo)?
+    } else if let Some((front, back)) = split_digits(num) {
+        stoney_gaze(front, blinks_left - 1, memo)? + stoney_gaze(back, blinks_left - 1, memo)?
+    } else {
+        stoney_gaze(num * 2024, blinks_left - 1, memo)?
+    };
+
+    // the stones all sit on the first line of the input
+    if let Some(key) = key {
+        let capacity = memo.capacity();
+        memo.insert(key, result).or_full("memo", capacity, 1)?;
+    }
+
+    Ok(result)
+}
+
+fn split_digits(num: u64) -> Option<(u64, u64)> {
+    let mut digits: u32 = 0;
+
+    for idx in 1..30u32 {
+        if num < 10u64.pow(idx) {
+            digits = idx;
+            break;
+        }
+    }
+
+    if digits.is_multiple_of(2) {
+        let front = num / 10u64.pow(digits / 2);
+        let back = num - (front * 10u64.pow(digits / 2));
+        return Some((front, back));
+    }
+
+    None
+}
+
+pub fn blink_stones<M: Memo<Key, u64>>(
+    input: &[u8],
+    blinks: u8,
+    memo: &mut M,
+) -> Result<u64, InputError> {
+    let mut scan = Scanner::new(input);
+    if scan.is_empty() {
+        return Err(scan.error(Expected::Thing("a line of stones")).into());
+    }
+    let mut total = 0;
+
+    loop {
+        total += stoney_gaze(scan.number()?, blinks, memo)?;
+        if scan.at_line_end() {
+            break;
+        }
+        scan.tag(" ")?;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use aoc_core::parse::{Found, ParseError};
+    use heapless::FnvIndexMap;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_split_digits() {
+        assert_eq!(split_digits(1000), Some((10, 0)));
+        assert_eq!(split_digits(253000), Some((253, 0)));
+        assert_eq!(split_digits(999), None);
+    }
+
+    #[test]
+    fn test_key() {
+        assert_eq!(key(125, 75), Some(75 << 57 | 125));
+        assert_eq!(key(1 << 57, 1), None);
+        assert_eq!(key(125, 128), None);
+        // a stone too big for a key still blinks, just without the memo
+        let mut memo: FnvIndexMap<Key, u64, 2> = FnvIndexMap::new();
+        assert_eq!(blink_stones(b"144115188075855872", 1, &mut memo), Ok(2));
+    }
+
+    #[test]
+    fn test_example_heapless() {
+        let mut memo: FnvIndexMap<Key, u64, 1024> = FnvIndexMap::new();
+        assert_eq!(blink_stones(b"125 17", 6, &mut memo), Ok(22));
+        let mut memo: FnvIndexMap<Key, u64, 1024> = FnvIndexMap::new();
+        assert_eq!(blink_stones(b"125 17", 25, &mut memo), Ok(55312));
+    }
+
+    #[test]
+    fn test_example_alloc() {
+        assert_eq!(blink_stones(b"125 17", 25, &mut HashMap::new()), Ok(55312));
+    }
+
+    #[test]
+    fn test_memo_full() {
+        let mut memo: FnvIndexMap<Key, u64, 16> = FnvIndexMap::new();
+        assert_eq!(
+            blink_stones(b"125 17", 25, &mut memo),
+            Err(InputError::Capacity(CapacityError {
+                buffer: "memo",
+                capacity: 16,
+                line: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_bad_stone() {
+        assert_eq!(
+            blink_stones(b"125 17 ", 25, &mut HashMap::new()),
+            Err(InputError::Parse(ParseError {
+                line: 1,
+                column: 8,
+                expected: Expected::Thing("a number"),
+                found: Found::EndOfLine,
+            }))
+        );
+    }
+}
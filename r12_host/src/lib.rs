@@ -0,0 +1,119 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_grid::{Dir, Pos, VecGrid};
+
+#[derive(Debug)]
+pub struct Map {
+    plants: VecGrid<u8>,
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, InputError> {
+        let plant = |chr: char| chr.is_ascii_uppercase().then_some(chr as u8);
+        let plants = VecGrid::parse(input, plant, "a plant")?;
+
+        Ok(Self { plants })
+    }
+
+    fn same_plant(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        self.plants
+            .step(pos, dir)
+            .filter(|&next| self.plants[next] == self.plants[pos])
+    }
+
+    fn has_fence(&self, pos: Pos, dir: Dir) -> bool {
+        self.same_plant(pos, dir).is_none()
+    }
+
+    fn perimeter(&self, pos: Pos) -> usize {
+        Dir::ALL
+            .into_iter()
+            .filter(|&dir| self.has_fence(pos, dir))
+            .count()
+    }
+
+    fn sides(&self, pos: Pos) -> usize {
+        // a fence only starts a side if the plot before it along the fence
+        // (above for east/west fences, to the left for north/south ones)
+        // doesn't carry on the same side
+        Dir::ALL
+            .into_iter()
+            .filter(|&dir| self.has_fence(pos, dir))
+            .filter(|&dir| {
+                let before = match dir {
+                    Dir::East | Dir::West => Dir::North,
+                    Dir::North | Dir::South => Dir::West,
+                };
+                !self
+                    .same_plant(pos, before)
+                    .is_some_and(|prev| self.has_fence(prev, dir))
+            })
+            .count()
+    }
+
+    fn flood_fill(&self) -> Vec<Vec<Pos>> {
+        let mut gardens = Vec::new();
+        let mut seen = VecGrid::new(self.plants.width(), self.plants.height(), false).unwrap();
+
+        for start in self.plants.positions() {
+            if seen[start] {
+                continue;
+            }
+
+            seen[start] = true;
+            let mut garden = vec![start];
+            let mut next = 0;
+
+            while let Some(&pos) = garden.get(next) {
+                next += 1;
+                for dir in Dir::ALL {
+                    if let Some(nbor) = self.same_plant(pos, dir)
+                        && !seen[nbor]
+                    {
+                        seen[nbor] = true;
+                        garden.push(nbor);
+                    }
+                }
+            }
+
+            gardens.push(garden);
+        }
+
+        gardens
+    }
+
+    fn solution(&self) -> (usize, usize) {
+        let gardens = self.flood_fill();
+
+        let part1 = gardens
+            .iter()
+            .map(|g| g.len() * g.iter().map(|&pos| self.perimeter(pos)).sum::<usize>())
+            .sum();
+
+        let part2 = gardens
+            .iter()
+            .map(|g| g.len() * g.iter().map(|&pos| self.sides(pos)).sum::<usize>())
+            .sum();
+
+        (part1, part2)
+    }
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Map::new(input)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Answer, InputError> {
+        Ok(map.solution().0.into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Answer, InputError> {
+        Ok(map.solution().1.into())
+    }
+}
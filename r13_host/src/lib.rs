@@ -1,3 +1,7 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::{Cursor, Lines, ParseError};
 use std::cmp::Ordering;
 
 const ACOST: u64 = 3;
@@ -5,34 +9,24 @@ const BCOST: u64 = 1;
 const LEARNING_RATIO: f64 = 10.0;
 const SEARCHES_PER_ROUND: usize = 100;
 
-#[derive(Debug)]
-struct ClawMachine {
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
     a_move: (u64, u64),
     b_move: (u64, u64),
     prize: (u64, u64),
 }
 
 impl ClawMachine {
-    fn new(input: &str) -> Self {
-        let mut line_iter = input.lines();
-
-        let mut split1 = line_iter.next().unwrap().split('+').skip(1);
-        let ax = split1.next().unwrap().split(',').next().unwrap();
-        let ay = split1.next().unwrap().trim();
-
-        let mut split2 = line_iter.next().unwrap().split('+').skip(1);
-        let bx = split2.next().unwrap().split(',').next().unwrap();
-        let by = split2.next().unwrap().trim();
-
-        let mut split3 = line_iter.next().unwrap().split('=').skip(1);
-        let prize_x = split3.next().unwrap().split(',').next().unwrap();
-        let prize_y = split3.next().unwrap().trim();
-
-        Self {
-            a_move: (ax.parse().unwrap(), ay.parse().unwrap()),
-            b_move: (bx.parse().unwrap(), by.parse().unwrap()),
-            prize: (prize_x.parse().unwrap(), prize_y.parse().unwrap()),
-        }
+    fn new(lines: &mut Lines) -> Result<Self, ParseError> {
+        let a_move = read_pair(lines.expect("button A")?, "Button A: X+", ", Y+")?;
+        let b_move = read_pair(lines.expect("button B")?, "Button B: X+", ", Y+")?;
+        let prize = read_pair(lines.expect("the prize")?, "Prize: X=", ", Y=")?;
+
+        Ok(Self {
+            a_move,
+            b_move,
+            prize,
+        })
     }
 
     fn test(&self, candidate: (u64, u64)) -> Ordering {
@@ -76,7 +70,7 @@ impl ClawMachine {
                     Ordering::Equal => {
                         return Some(ACOST * a_pushes + BCOST * b_pushes);
                     }
-                    Ordering::Greater => b_pushes -= 1,
+                    Ordering::Greater => b_pushes = b_pushes.checked_sub(1)?,
                 }
                 if limit {
                     return None;
@@ -105,17 +99,59 @@ impl ClawMachine {
     }
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+fn read_pair(
+    mut line: Cursor,
+    before_x: &'static str,
+    before_y: &'static str,
+) -> Result<(u64, u64), ParseError> {
+    line.tag(before_x)?;
+    let x = line.number()?;
+    line.tag(before_y)?;
+    let y = line.number()?;
+    line.end()?;
+    Ok((x, y))
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<ClawMachine>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        let mut lines = Lines::new(input);
+        let mut machines = Vec::new();
 
-    let mut machines: Vec<_> = input.split("\n\n").map(ClawMachine::new).collect();
-    let ans1 = machines.iter().filter_map(|m| m.optimise()).sum::<u64>();
-    dbg!(ans1);
+        loop {
+            machines.push(ClawMachine::new(&mut lines)?);
+            // machines are separated by a blank line
+            match lines.next() {
+                Some(line) => line.end()?,
+                None => break,
+            }
+        }
+
+        Ok(machines)
+    }
+
+    fn part1(&self, machines: &Self::Input) -> Result<Answer, InputError> {
+        Ok(machines
+            .iter()
+            .filter_map(|m| m.optimise())
+            .sum::<u64>()
+            .into())
+    }
+
+    fn part2(&self, machines: &Self::Input) -> Result<Answer, InputError> {
+        let increase = 10_000_000_000_000u64;
+        let mut machines = machines.clone();
+        for machine in machines.iter_mut() {
+            machine.prize = (machine.prize.0 + increase, machine.prize.1 + increase);
+        }
 
-    let increase = 10_000_000_000_000u64;
-    for machine in machines.iter_mut() {
-        machine.prize = (machine.prize.0 + increase, machine.prize.1 + increase);
+        Ok(machines
+            .iter()
+            .filter_map(|m| m.optimise())
+            .sum::<u64>()
+            .into())
     }
-    let ans2 = machines.iter().filter_map(|m| m.optimise()).sum::<u64>();
-    dbg!(ans2);
 }
@@ -0,0 +1,212 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::{InputError, NoAnswer};
+use aoc_core::parse::{self, Cursor, Expected, Lines, ParseError};
+use aoc_grid::Pos;
+use aoc_vis::Frame;
+use std::collections::HashSet;
+
+fn wrap(a: i16, b: i16, max: i16) -> i16 {
+    ((a + b) % max + max) % max
+}
+
+/// The room the real puzzle's bots are in; the example's is 11 by 7.
+pub const DIMENSIONS: (i16, i16) = (101, 103);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Bot {
+    pos: (i16, i16),
+    vel: (i16, i16),
+}
+
+impl Bot {
+    fn new(mut line: Cursor, dimensions: (i16, i16)) -> Result<Self, ParseError> {
+        line.tag("p=")?;
+        let pos_x = Self::read_coord(&mut line, dimensions.0)?;
+        line.tag(",")?;
+        let pos_y = Self::read_coord(&mut line, dimensions.1)?;
+        line.tag(" v=")?;
+        let vel_x = line.number()?;
+        line.tag(",")?;
+        let vel_y = line.number()?;
+        line.end()?;
+
+        Ok(Self {
+            pos: (pos_x, pos_y),
+            vel: (vel_x, vel_y),
+        })
+    }
+
+    fn read_coord(line: &mut Cursor, size: i16) -> Result<i16, ParseError> {
+        let start = line.clone();
+        match line.number()? {
+            coord if (0..size).contains(&coord) => Ok(coord),
+            _ => Err(start.error(Expected::Thing("a position inside the room"))),
+        }
+    }
+
+    fn step(&mut self, dimensions: (i16, i16)) {
+        self.pos.0 = wrap(self.pos.0, self.vel.0, dimensions.0);
+        self.pos.1 = wrap(self.pos.1, self.vel.1, dimensions.1);
+    }
+
+    fn quadrant(&self, dimensions: (i16, i16)) -> Option<usize> {
+        if self.pos.0 < (dimensions.0 / 2) {
+            if self.pos.1 < (dimensions.1 / 2) {
+                return Some(0);
+            }
+
+            if self.pos.1 > (dimensions.1 / 2) {
+                return Some(1);
+            }
+        }
+
+        if self.pos.0 > (dimensions.0 / 2) {
+            if self.pos.1 < (dimensions.1 / 2) {
+                return Some(2);
+            }
+
+            if self.pos.1 > (dimensions.1 / 2) {
+                return Some(3);
+            }
+        }
+
+        None
+    }
+}
+
+/// The bots, and the room they wrap around.
+#[derive(Debug, Clone)]
+pub struct Room {
+    bots: Vec<Bot>,
+    dimensions: (i16, i16),
+}
+
+impl Room {
+    fn step(&mut self) {
+        let dimensions = self.dimensions;
+        self.bots.iter_mut().for_each(|b| b.step(dimensions));
+    }
+
+    fn frame(&self, seconds: usize) -> Frame {
+        let (width, height) = self.dimensions;
+        let mut frame = Frame::new(width as usize, height as usize, ' ');
+        for bot in &self.bots {
+            frame[Pos::new(bot.pos.1 as usize, bot.pos.0 as usize)] = '#';
+        }
+        frame.caption(format!("after {seconds} seconds"))
+    }
+}
+
+/// Every second until the bots are back where they started, which has to
+/// happen by the time each has been round both ways.
+pub fn frames(mut room: Room) -> impl Iterator<Item = Frame> {
+    let period = room.dimensions.0 as usize * room.dimensions.1 as usize;
+    (0..period).map(move |seconds| {
+        if seconds > 0 {
+            room.step();
+        }
+        room.frame(seconds)
+    })
+}
+
+fn closeness(bots: &[Bot]) -> usize {
+    let pos: HashSet<_> = bots.iter().map(|b| b.pos).collect();
+
+    let mut touching = 0;
+    for bot1 in pos.iter() {
+        for bot2 in pos.iter() {
+            if bot1 != bot2 && (bot1.0 - bot2.0).abs() <= 1 && (bot1.1 - bot2.1).abs() <= 1 {
+                touching += 1;
+                break;
+            }
+        }
+    }
+
+    touching * 100 / pos.len()
+}
+
+pub struct Day14 {
+    /// Width and height of the room.
+    pub dimensions: (i16, i16),
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            dimensions: DIMENSIONS,
+        }
+    }
+}
+
+impl Solver for Day14 {
+    type Input = Room;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        let bots: Vec<_> = Lines::new(input)
+            .map(|line| Bot::new(line, self.dimensions))
+            .collect::<Result<_, _>>()?;
+        // part 2 looks for them gathering
+        if bots.is_empty() {
+            return Err(parse::missing(input, "a robot").into());
+        }
+
+        Ok(Room {
+            bots,
+            dimensions: self.dimensions,
+        })
+    }
+
+    fn part1(&self, room: &Self::Input) -> Result<Answer, InputError> {
+        let mut room = room.clone();
+        for _ in 1..=100 {
+            room.step();
+        }
+
+        let mut quads = [0u32; 4];
+        for bot in room.bots.iter() {
+            if let Some(quad) = bot.quadrant(room.dimensions) {
+                quads[quad] += 1;
+            }
+        }
+
+        Ok((quads[0] * quads[1] * quads[2] * quads[3]).into())
+    }
+
+    fn part2(&self, room: &Self::Input) -> Result<Answer, InputError> {
+        // after this the bots are back where they started, as in `frames`
+        let period = room.dimensions.0 as usize * room.dimensions.1 as usize;
+        let mut room = room.clone();
+        for step in 1..period {
+            room.step();
+
+            if closeness(&room.bots) > 60 {
+                return Ok(step.into());
+            }
+        }
+
+        Err(NoAnswer {
+            missing: "the bots never gather into a picture",
+        }
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::parse::Found;
+
+    #[test]
+    fn test_no_bots() {
+        assert_eq!(
+            Day14::default().parse("").err(),
+            Some(InputError::Parse(ParseError {
+                line: 1,
+                column: 1,
+                expected: Expected::Thing("a robot"),
+                found: Found::EndOfInput,
+            }))
+        );
+    }
+}
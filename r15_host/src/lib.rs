@@ -0,0 +1,337 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::{Expected, Found, Lines, ParseError};
+use aoc_grid::{Dir, Pos, VecGrid};
+use aoc_vis::Frame;
+use std::iter;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Tile {
+    Floor,
+    Wall,
+    Box,
+    LeftBox,
+    RightBox,
+    Robot,
+}
+
+/// The warehouse as drawn in the input, before either part stretches it.
+struct Plan {
+    tiles: VecGrid<Tile>,
+    bot: Pos,
+    /// Backwards, so the next one can be popped off the end.
+    instructions: Vec<Dir>,
+}
+
+fn read_plan(input: &str) -> Result<Plan, InputError> {
+    let mut lines = Lines::new(input);
+
+    let tile = |chr| match chr {
+        '.' => Some(Tile::Floor),
+        '#' => Some(Tile::Wall),
+        'O' => Some(Tile::Box),
+        '@' => Some(Tile::Robot),
+        _ => None,
+    };
+    let mut tiles = VecGrid::read(&mut lines, tile, "a wall, box or floor")?;
+
+    // the moves rely on this to keep the robot and boxes on the map
+    let (width, height) = (tiles.width(), tiles.height());
+    let edge =
+        |pos: Pos| pos.row == 0 || pos.col == 0 || pos.row == height - 1 || pos.col == width - 1;
+    if let Some((pos, &tile)) = tiles
+        .iter()
+        .find(|&(pos, &tile)| edge(pos) && tile != Tile::Wall)
+    {
+        return Err(ParseError {
+            line: pos.row + 1,
+            column: pos.col + 1,
+            expected: Expected::Thing("a wall around the edge"),
+            found: Found::Char(match tile {
+                Tile::Box => 'O',
+                Tile::Robot => '@',
+                _ => '.',
+            }),
+        }
+        .into());
+    }
+
+    let Some(bot) = tiles.find(|&tile| tile == Tile::Robot) else {
+        return Err(ParseError {
+            line: tiles.height() + 1,
+            column: 1,
+            expected: Expected::Thing("the robot somewhere on the map"),
+            found: Found::EndOfLine,
+        }
+        .into());
+    };
+    tiles[bot] = Tile::Floor;
+
+    if let Some(other) = tiles.find(|&tile| tile == Tile::Robot) {
+        return Err(ParseError {
+            line: other.row + 1,
+            column: other.col + 1,
+            expected: Expected::Thing("a wall, box or floor"),
+            found: Found::Char('@'),
+        }
+        .into());
+    }
+    lines.next();
+
+    let mut instructions = Vec::new();
+    let mut line = lines.expect("the moves")?;
+    loop {
+        while !line.is_empty() {
+            instructions.push(match line.char(|chr| "^>v<".contains(chr), "a move")? {
+                '^' => Dir::North,
+                '>' => Dir::East,
+                'v' => Dir::South,
+                _ => Dir::West,
+            });
+        }
+        match lines.next() {
+            Some(next) => line = next,
+            None => break,
+        }
+    }
+    instructions.reverse();
+
+    Ok(Plan {
+        tiles,
+        bot,
+        instructions,
+    })
+}
+
+/// `read_plan` checks the warehouse is walled in, so the robot and boxes
+/// never step off it.
+fn next_pos(pos: Pos, dir: Dir) -> Pos {
+    pos.offset(dir).expect("the warehouse is walled in")
+}
+
+#[derive(Debug, Clone)]
+pub struct Map2 {
+    tiles: VecGrid<Tile>,
+    bot: Pos,
+    instructions: Vec<Dir>,
+}
+
+impl Map2 {
+    fn new(plan: &Plan) -> Self {
+        let (width, height) = (plan.tiles.width(), plan.tiles.height());
+        let mut tiles = VecGrid::new(width * 2, height, Tile::Floor).unwrap();
+        for (pos, &tile) in plan.tiles.iter() {
+            let (left, right) = match tile {
+                Tile::Wall => (Tile::Wall, Tile::Wall),
+                Tile::Box => (Tile::LeftBox, Tile::RightBox),
+                _ => continue,
+            };
+            tiles[Pos::new(pos.row, pos.col * 2)] = left;
+            tiles[Pos::new(pos.row, pos.col * 2 + 1)] = right;
+        }
+
+        Self {
+            tiles,
+            bot: Pos::new(plan.bot.row, plan.bot.col * 2),
+            instructions: plan.instructions.clone(),
+        }
+    }
+
+    fn can_clear_space(&mut self, pos: Pos, dir: Dir, internal: bool) -> bool {
+        let other_half = match self.tiles[pos] {
+            Tile::Wall => return false,
+            Tile::LeftBox => Pos::new(pos.row, pos.col + 1),
+            Tile::RightBox => Pos::new(pos.row, pos.col - 1),
+            _ => return true,
+        };
+
+        let mut outcome = self.can_clear_space(next_pos(pos, dir), dir, false);
+
+        if !internal && [Dir::North, Dir::South].contains(&dir) {
+            outcome = outcome && self.can_clear_space(other_half, dir, true);
+        }
+
+        outcome
+    }
+
+    fn do_clear_space(&mut self, pos: Pos, dir: Dir, internal: bool) {
+        let other_half = match self.tiles[pos] {
+            Tile::Wall => unreachable!(),
+            Tile::LeftBox => Pos::new(pos.row, pos.col + 1),
+            Tile::RightBox => Pos::new(pos.row, pos.col - 1),
+            _ => return,
+        };
+
+        let next_pos = next_pos(pos, dir);
+        self.do_clear_space(next_pos, dir, false);
+        self.tiles[next_pos] = self.tiles[pos];
+        self.tiles[pos] = Tile::Floor;
+
+        if !internal && [Dir::North, Dir::South].contains(&dir) {
+            self.do_clear_space(other_half, dir, true);
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if let Some(dir) = self.instructions.pop() {
+            let next_pos = next_pos(self.bot, dir);
+            if self.can_clear_space(next_pos, dir, false) {
+                self.do_clear_space(next_pos, dir, false);
+                self.bot = next_pos;
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn score(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|&(_, &tile)| tile == Tile::LeftBox)
+            .map(|(pos, _)| pos.row * 100 + pos.col)
+            .sum::<usize>()
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::grid(&self.tiles, |pos, tile| match tile {
+            _ if pos == self.bot => '@',
+            Tile::LeftBox => '[',
+            Tile::RightBox => ']',
+            Tile::Wall => '#',
+            _ => ' ',
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    tiles: VecGrid<Tile>,
+    bot: Pos,
+    instructions: Vec<Dir>,
+}
+
+impl Map {
+    fn new(plan: &Plan) -> Self {
+        Self {
+            tiles: plan.tiles.clone(),
+            bot: plan.bot,
+            instructions: plan.instructions.clone(),
+        }
+    }
+
+    fn clear_space(&mut self, pos: Pos, dir: Dir) -> bool {
+        match self.tiles[pos] {
+            Tile::Wall => false,
+            Tile::Box => {
+                let next_pos = next_pos(pos, dir);
+                let space_is_clear = self.clear_space(next_pos, dir);
+
+                if space_is_clear {
+                    self.tiles[next_pos] = Tile::Box;
+                    self.tiles[pos] = Tile::Floor;
+                }
+                space_is_clear
+            }
+            _ => true,
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if let Some(dir) = self.instructions.pop() {
+            let next_pos = next_pos(self.bot, dir);
+            if self.clear_space(next_pos, dir) {
+                self.bot = next_pos;
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn score(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|&(_, &tile)| tile == Tile::Box)
+            .map(|(pos, _)| pos.row * 100 + pos.col)
+            .sum::<usize>()
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::grid(&self.tiles, |pos, tile| match tile {
+            _ if pos == self.bot => '@',
+            Tile::Box => 'O',
+            Tile::Wall => '#',
+            _ => ' ',
+        })
+    }
+}
+
+/// The robot working through its moves, in part 1's warehouse and then
+/// part 2's.
+pub fn frames((map, map2): (Map, Map2)) -> impl Iterator<Item = Frame> {
+    let moves = map.instructions.len();
+    let part1 = iter::successors(Some(map), |map| {
+        let mut map = map.clone();
+        map.step().then_some(map)
+    })
+    .enumerate()
+    .map(move |(done, map)| {
+        map.frame()
+            .caption(format!("part 1, move {done} of {moves}"))
+    });
+    let part2 = iter::successors(Some(map2), |map| {
+        let mut map = map.clone();
+        map.step().then_some(map)
+    })
+    .enumerate()
+    .map(move |(done, map)| {
+        map.frame()
+            .caption(format!("part 2, move {done} of {moves}"))
+    });
+
+    part1.chain(part2)
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = (Map, Map2);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        let plan = read_plan(input)?;
+        Ok((Map::new(&plan), Map2::new(&plan)))
+    }
+
+    fn part1(&self, (map, _): &Self::Input) -> Result<Answer, InputError> {
+        let mut map = map.clone();
+        while map.step() {}
+        Ok(map.score().into())
+    }
+
+    fn part2(&self, (_, map): &Self::Input) -> Result<Answer, InputError> {
+        let mut map = map.clone();
+        while map.step() {}
+        Ok(map.score().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_wall() {
+        let err = Day15.parse("@.O.\n#..#\n\n>>>>").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected a wall around the edge, found '@'"
+        );
+        let err = Day15.parse("####\n#@..\n####\n\n>>>>").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected a wall around the edge, found '.'"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::{InputError, NoAnswer};
+use aoc_core::parse::missing;
+use aoc_grid::{Dir, Pos, VecGrid};
+use aoc_search::{SearchProblem, all_shortest_paths, astar};
+use aoc_vis::Frame;
+use std::collections::HashSet;
+use std::iter;
+
+const TURN_COST: usize = 1000;
+const MOVE_COST: usize = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Floor,
+    Start,
+    End,
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    tiles: VecGrid<Tile>,
+    start: Pos,
+    end: Pos,
+}
+
+/// The reindeer can step forward or turn on the spot, and turns are dear.
+impl SearchProblem for Maze {
+    type State = (Pos, Dir);
+
+    fn start(&self) -> (Pos, Dir) {
+        (self.start, Dir::East)
+    }
+
+    fn successors(
+        &self,
+        &(pos, dir): &(Pos, Dir),
+    ) -> impl IntoIterator<Item = ((Pos, Dir), usize)> {
+        let forward = self
+            .tiles
+            .step(pos, dir)
+            .filter(|&next| self.tiles[next] != Tile::Wall)
+            .map(|next| ((next, dir), MOVE_COST));
+        let turns = [dir.turn_left(), dir.turn_right()].map(|turned| ((pos, turned), TURN_COST));
+
+        forward.into_iter().chain(turns)
+    }
+
+    fn is_goal(&self, &(pos, _): &(Pos, Dir)) -> bool {
+        pos == self.end
+    }
+
+    fn heuristic(&self, &(pos, _): &(Pos, Dir)) -> usize {
+        pos.manhattan(self.end) * MOVE_COST
+    }
+}
+
+fn read_maze(input: &str) -> Result<Maze, InputError> {
+    let tile = |chr| match chr {
+        '#' => Some(Tile::Wall),
+        '.' => Some(Tile::Floor),
+        'S' => Some(Tile::Start),
+        'E' => Some(Tile::End),
+        _ => None,
+    };
+    let tiles = VecGrid::parse(input, tile, "a wall, floor, S or E")?;
+
+    let start = tiles
+        .find(|&tile| tile == Tile::Start)
+        .ok_or(missing(input, "the start"))?;
+    let end = tiles
+        .find(|&tile| tile == Tile::End)
+        .ok_or(missing(input, "the end"))?;
+
+    Ok(Maze { tiles, start, end })
+}
+
+/// The reindeer walking one of the cheapest paths, and then every seat on
+/// any of them.
+pub fn frames(maze: Maze) -> impl Iterator<Item = Frame> {
+    let path = astar(&maze).map_or(Vec::new(), |path| path.states);
+    let seats: HashSet<_> = all_shortest_paths(&maze)
+        .map(|dag| dag.states().into_iter().map(|(pos, _)| pos).collect())
+        .unwrap_or_default();
+
+    let mut frame = Frame::grid(&maze.tiles, |_, &tile| match tile {
+        Tile::Wall => '#',
+        _ => ' ',
+    });
+    let mut seated = frame.clone();
+    for &seat in &seats {
+        seated[seat] = 'O';
+    }
+    let seated = seated.caption(format!("{} seats on the best paths", seats.len()));
+
+    let mut score = 0;
+    let mut last = None;
+    let walk = path.into_iter().map(move |(pos, dir)| {
+        if let Some(last) = last.replace(pos) {
+            score += if last == pos { TURN_COST } else { MOVE_COST };
+            frame[last] = '.';
+        }
+        frame[pos] = match dir {
+            Dir::North => '^',
+            Dir::East => '>',
+            Dir::South => 'v',
+            Dir::West => '<',
+        };
+        frame.clone().caption(format!("score {score}"))
+    });
+
+    walk.chain(iter::once(seated))
+}
+
+const NO_WAY: NoAnswer = NoAnswer {
+    missing: "there is no way from the start to the end",
+};
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = Maze;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        read_maze(input)
+    }
+
+    fn part1(&self, maze: &Self::Input) -> Result<Answer, InputError> {
+        let path = astar(maze).ok_or(NO_WAY)?;
+        Ok(path.cost.into())
+    }
+
+    fn part2(&self, maze: &Self::Input) -> Result<Answer, InputError> {
+        let dag = all_shortest_paths(maze).ok_or(NO_WAY)?;
+        let seats: HashSet<_> = dag.states().into_iter().map(|(pos, _)| pos).collect();
+        Ok(seats.len().into())
+    }
+}
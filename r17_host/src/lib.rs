@@ -1,7 +1,12 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::{InputError, NoAnswer};
+use aoc_core::parse::{Lines, ParseError};
+
 const REGISTERS: usize = 3;
 
-#[derive(Debug)]
-struct Computer {
+#[derive(Debug, Clone)]
+pub struct Computer {
     registers: [u64; REGISTERS],
     program: Vec<u64>,
     output: Vec<u64>,
@@ -9,25 +14,35 @@ struct Computer {
 }
 
 impl Computer {
-    fn new(input: &str) -> Option<Self> {
-        let mut lines = input.lines();
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
         let mut registers = [0; REGISTERS];
-        for reg in registers.iter_mut() {
-            *reg = lines.next()?.split(": ").nth(1)?.parse().unwrap();
+        for (reg, name) in registers.iter_mut().zip(["A", "B", "C"]) {
+            let mut line = lines.expect("a register")?;
+            line.tag("Register ")?;
+            line.tag(name)?;
+            line.tag(": ")?;
+            *reg = line.number()?;
+            line.end()?;
         }
 
-        let program = lines
-            .nth(1)?
-            .split(": ")
-            .nth(1)?
-            .split(',')
-            .filter_map(|s| s.parse::<u64>().ok())
-            .collect();
+        lines.expect("a blank line")?.end()?;
+        let mut line = lines.expect("the program")?;
+        line.tag("Program: ")?;
+        let mut program = Vec::new();
+        loop {
+            let code = line.char(|chr| ('0'..='7').contains(&chr), "a 3-bit number")?;
+            program.push(code as u64 - '0' as u64);
+            if line.is_empty() {
+                break;
+            }
+            line.tag(",")?;
+        }
 
         let instruction_pointer = 0;
 
         let output = Vec::new();
-        Some(Computer {
+        Ok(Computer {
             registers,
             program,
             output,
@@ -127,42 +142,56 @@ impl Computer {
     }
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let mut cmp = Computer::new(input).unwrap();
-    cmp.run();
-    dbg!(cmp.print_output());
+pub struct Day17;
 
-    let mut candidates = Vec::new();
-    for val in 0..=1023 {
-        let mut cmp = Computer::new(input).unwrap();
-        cmp.registers[0] = val;
-        if cmp.quiness() > 0 {
-            candidates.push(val);
-        }
+impl Solver for Day17 {
+    type Input = Computer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(Computer::new(input)?)
     }
 
-    for completion in 1..=15 {
-        candidates = candidates
-            .into_iter()
-            .flat_map(|c| {
-                (0..8)
-                    .filter_map(|nc| {
-                        let mut cmp = Computer::new(input).unwrap();
-                        let new_candidate = (nc << (7 + 3 * completion)) + c;
-                        cmp.registers[0] = new_candidate;
-                        if cmp.quiness() > completion {
-                            Some(new_candidate)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<Vec<u64>>()
-            })
-            .collect::<Vec<u64>>();
+    fn part1(&self, computer: &Self::Input) -> Result<Answer, InputError> {
+        let mut cmp = computer.clone();
+        cmp.run();
+        Ok(cmp.print_output().into())
     }
 
-    dbg!(candidates.into_iter().min().unwrap());
+    fn part2(&self, computer: &Self::Input) -> Result<Answer, InputError> {
+        let mut candidates = Vec::new();
+        for val in 0..=1023 {
+            let mut cmp = computer.clone();
+            cmp.registers[0] = val;
+            if cmp.quiness() > 0 {
+                candidates.push(val);
+            }
+        }
+
+        for completion in 1..=15 {
+            candidates = candidates
+                .into_iter()
+                .flat_map(|c| {
+                    (0..8)
+                        .filter_map(|nc| {
+                            let mut cmp = computer.clone();
+                            let new_candidate = (nc << (7 + 3 * completion)) + c;
+                            cmp.registers[0] = new_candidate;
+                            if cmp.quiness() > completion {
+                                Some(new_candidate)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect::<Vec<u64>>()
+                })
+                .collect::<Vec<u64>>();
+        }
+
+        let quine = candidates.into_iter().min().ok_or(NoAnswer {
+            missing: "nothing in register A makes the program print itself",
+        })?;
+        Ok(quine.into())
+    }
 }
 
 #[cfg(test)]
@@ -182,6 +211,22 @@ Program: 0,1,5,4,3,0";
         assert_eq!(cmp.print_output(), "4,6,3,5,6,3,5,2,1,0");
     }
 
+    #[test]
+    fn test_bad_program() {
+        let input = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,8";
+
+        let err = Computer::new(input).unwrap_err();
+        assert_eq!((err.line, err.column), (5, 14));
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 14: expected a 3-bit number, found '8'"
+        );
+    }
+
     #[test]
     fn test_opcode2() {
         let mut cmp = Computer {
@@ -0,0 +1,174 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::{InputError, NoAnswer};
+use aoc_core::parse::{Cursor, Expected, Lines, ParseError};
+use aoc_grid::{Pos, VecGrid};
+use aoc_search::{SearchProblem, bfs};
+use aoc_vis::Frame;
+use std::iter;
+
+/// The side of the real puzzle's memory space; the example's is 7.
+pub const SIZE: usize = 71;
+/// The bytes that have fallen when part 1 looks in the real puzzle; the
+/// example has 12.
+pub const DROPS: usize = 1024;
+
+#[derive(Debug, Clone)]
+pub struct MemoryBank {
+    corrupted_bits: VecGrid<bool>,
+    incoming: Vec<Pos>,
+    end: Pos,
+    drops: usize,
+}
+
+impl MemoryBank {
+    fn new(input: &str, width: usize, height: usize, drops: usize) -> Result<Self, InputError> {
+        let corrupted_bits = VecGrid::new(width, height, false)?;
+        let end = Pos::new(height - 1, width - 1);
+
+        // X is across, then Y down
+        let mut incoming = Lines::new(input)
+            .map(|mut line| {
+                let x = Self::read_coord(&mut line, end.col)?;
+                line.tag(",")?;
+                let y = Self::read_coord(&mut line, end.row)?;
+                line.end()?;
+                Ok(Pos::new(y, x))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        incoming.reverse();
+
+        Ok(MemoryBank {
+            corrupted_bits,
+            incoming,
+            end,
+            drops,
+        })
+    }
+
+    fn read_coord(line: &mut Cursor, max: usize) -> Result<usize, ParseError> {
+        let start = line.clone();
+        match line.number()? {
+            coord if coord <= max => Ok(coord),
+            _ => Err(start.error(Expected::Thing("a coordinate inside the memory space"))),
+        }
+    }
+
+    fn next_fall(&mut self) -> Option<Pos> {
+        let bit = self.incoming.pop()?;
+        self.corrupted_bits[bit] = true;
+        Some(bit)
+    }
+
+    fn many_fall(&mut self, bits: usize) {
+        for _ in 0..bits {
+            self.next_fall();
+        }
+    }
+}
+
+/// Running from the top left to the exit, around whatever has fallen so far.
+impl SearchProblem for MemoryBank {
+    type State = Pos;
+
+    fn start(&self) -> Pos {
+        Pos::new(0, 0)
+    }
+
+    fn successors(&self, &pos: &Pos) -> impl IntoIterator<Item = (Pos, usize)> {
+        self.corrupted_bits
+            .neighbours(pos)
+            .filter(|&next| !self.corrupted_bits[next])
+            .map(|next| (next, 1))
+    }
+
+    fn is_goal(&self, &pos: &Pos) -> bool {
+        pos == self.end
+    }
+}
+
+fn traverse(mut mb: MemoryBank, initial_drops: usize) -> Option<usize> {
+    mb.many_fall(initial_drops);
+    bfs(&mb).map(|path| path.cost)
+}
+
+/// The bytes falling one at a time, with the shortest way out around them,
+/// until there isn't one.
+pub fn frames(mut mb: MemoryBank) -> impl Iterator<Item = Frame> {
+    let mut fallen = 0;
+    let mut last = None;
+    let mut finished = false;
+
+    iter::from_fn(move || {
+        if finished {
+            return None;
+        }
+
+        let mut frame = Frame::grid(&mb.corrupted_bits, |_, &bit| if bit { '#' } else { ' ' });
+        match (bfs(&mb), last) {
+            (Some(path), _) => {
+                for pos in path.states {
+                    frame[pos] = 'O';
+                }
+                frame.caption = format!("{fallen} bytes down, {} steps out", path.cost);
+            }
+            (None, Some(bit)) => {
+                frame[bit] = 'X';
+                frame.caption = format!("cut off by the byte at {},{}", bit.col, bit.row);
+                finished = true;
+            }
+            (None, None) => finished = true,
+        }
+
+        last = mb.next_fall();
+        fallen += 1;
+        finished |= last.is_none();
+        Some(frame)
+    })
+}
+
+pub struct Day18 {
+    pub width: usize,
+    pub height: usize,
+    /// Bytes fallen when part 1 looks, and where part 2 starts looking.
+    pub drops: usize,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Self {
+            width: SIZE,
+            height: SIZE,
+            drops: DROPS,
+        }
+    }
+}
+
+impl Solver for Day18 {
+    type Input = MemoryBank;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        MemoryBank::new(input, self.width, self.height, self.drops)
+    }
+
+    fn part1(&self, mb: &Self::Input) -> Result<Answer, InputError> {
+        let steps = traverse(mb.clone(), mb.drops).ok_or(NoAnswer {
+            missing: "the exit is already cut off",
+        })?;
+        Ok(steps.into())
+    }
+
+    fn part2(&self, mb: &Self::Input) -> Result<Answer, InputError> {
+        for initial_drops in mb.drops..=mb.incoming.len() {
+            if traverse(mb.clone(), initial_drops).is_none() {
+                let bit = mb.incoming.iter().rev().nth(initial_drops - 1).unwrap();
+                return Ok(format!("{},{}", bit.col, bit.row).into());
+            }
+        }
+
+        Err(NoAnswer {
+            missing: "the exit is never cut off",
+        }
+        .into())
+    }
+}
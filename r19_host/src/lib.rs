@@ -0,0 +1,121 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::{Cursor, Expected, Lines, ParseError};
+use std::collections::VecDeque;
+
+fn match_pattern(pattern: &str, towels: &[Vec<u8>]) -> u64 {
+    let mut ways_to_solve_this_line = 0;
+    let mut queue: VecDeque<(Vec<u8>, u64)> = VecDeque::new();
+    let characters = pattern.chars().rev().map(|c| c as u8).collect();
+    queue.push_front((characters, 1));
+
+    while let Some((stub, count)) = queue.pop_front() {
+        if stub.is_empty() {
+            ways_to_solve_this_line += count;
+        }
+
+        for towel in towels.iter() {
+            if towel.len() <= stub.len()
+                && towel
+                    .iter()
+                    .zip(stub.iter().rev())
+                    .all(|(c1, c2)| *c1 == *c2)
+            {
+                let mut next_stub = stub.clone();
+                for _ in 0..towel.len() {
+                    let _ = next_stub.pop();
+                }
+
+                let mut found = false;
+                for (other_stub, other_count) in queue.iter_mut() {
+                    if *other_stub == next_stub {
+                        *other_count += count;
+                        found = true;
+                        break;
+                    }
+                }
+
+                if !found {
+                    queue.push_back((next_stub, count));
+                }
+            }
+        }
+    }
+
+    ways_to_solve_this_line
+}
+
+#[derive(Debug)]
+pub struct Onsen {
+    towels: Vec<Vec<u8>>,
+    designs: Vec<String>,
+}
+
+fn stripes<'a>(line: &mut Cursor<'a>) -> Result<&'a str, ParseError> {
+    let stripes = line.take_while(|chr| "wubrg".contains(chr));
+    if stripes.is_empty() {
+        return Err(line.error(Expected::Thing("a stripe colour")));
+    }
+    Ok(stripes)
+}
+
+impl Onsen {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
+
+        let mut line = lines.expect("the towels")?;
+        let mut towels = Vec::new();
+        loop {
+            towels.push(stripes(&mut line)?.bytes().collect());
+            if line.is_empty() {
+                break;
+            }
+            line.tag(", ")?;
+        }
+        lines.expect("a blank line")?.end()?;
+
+        let designs = lines
+            .map(|mut line| {
+                let design = stripes(&mut line)?;
+                line.end()?;
+                Ok(design.to_owned())
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { towels, designs })
+    }
+}
+
+fn matchymatchy(onsen: &Onsen) -> (u64, u64) {
+    let mut possible_designs = 0;
+    let mut possible_ways = 0;
+
+    for design in onsen.designs.iter() {
+        let possible_ways_of_matching = match_pattern(design, &onsen.towels);
+        if possible_ways_of_matching > 0 {
+            possible_designs += 1;
+        }
+        possible_ways += possible_ways_of_matching;
+    }
+
+    (possible_designs, possible_ways)
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = Onsen;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(Onsen::new(input)?)
+    }
+
+    fn part1(&self, onsen: &Self::Input) -> Result<Answer, InputError> {
+        Ok(matchymatchy(onsen).0.into())
+    }
+
+    fn part2(&self, onsen: &Self::Input) -> Result<Answer, InputError> {
+        Ok(matchymatchy(onsen).1.into())
+    }
+}
@@ -0,0 +1,108 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::missing;
+use aoc_grid::{Pos, VecGrid};
+use aoc_search::{SearchProblem, distances};
+
+/// Racing along the track without cheating.
+struct Racetrack {
+    walls: VecGrid<bool>,
+    start: Pos,
+}
+
+impl SearchProblem for Racetrack {
+    type State = Pos;
+
+    fn start(&self) -> Pos {
+        self.start
+    }
+
+    fn successors(&self, &pos: &Pos) -> impl IntoIterator<Item = (Pos, usize)> {
+        self.walls
+            .neighbours(pos)
+            .filter(|&next| !self.walls[next])
+            .map(|next| (next, 1))
+    }
+
+    fn is_goal(&self, _: &Pos) -> bool {
+        false
+    }
+}
+
+/// The track from S to E, in the order it's raced.
+fn parse_track(input: &str) -> Result<Vec<Pos>, InputError> {
+    let tile = |chr| "#.SE".contains(chr).then_some(chr);
+    let tiles = VecGrid::parse(input, tile, "a wall, track, S or E")?;
+
+    let start = tiles
+        .find(|&tile| tile == 'S')
+        .ok_or(missing(input, "the start"))?;
+    let end = tiles
+        .find(|&tile| tile == 'E')
+        .ok_or(missing(input, "the end"))?;
+
+    let mut walls = VecGrid::new(tiles.width(), tiles.height(), false)?;
+    for (pos, &tile) in tiles.iter() {
+        walls[pos] = tile == '#';
+    }
+
+    let distances = distances(&Racetrack { walls, start });
+    let Some(&length) = distances.get(&end) else {
+        return Err(missing(input, "a track from S to E").into());
+    };
+
+    // there's only the one track, so its cells are all this far along it
+    let mut ordered_track = vec![start; length + 1];
+    for (pos, distance) in distances {
+        if distance <= length {
+            ordered_track[distance] = pos;
+        }
+    }
+
+    Ok(ordered_track)
+}
+
+fn count_short_cuts(track: &[Pos], max_cheat: usize, faster: usize) -> u32 {
+    let mut total = 0;
+    for (earlier_idx, earlier_loc) in track.iter().enumerate() {
+        for (later_idx, later_loc) in track.iter().enumerate().skip(earlier_idx + 1) {
+            let dist = earlier_loc.manhattan(*later_loc);
+            if (2..=max_cheat).contains(&dist) && (later_idx - earlier_idx - dist) >= faster {
+                total += 1;
+            }
+        }
+    }
+
+    total
+}
+
+/// The fewest picoseconds a cheat has to save to count in the real puzzle.
+pub const SAVING: usize = 100;
+
+pub struct Day20 {
+    /// The fewest picoseconds a cheat has to save to count.
+    pub saving: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self { saving: SAVING }
+    }
+}
+
+impl Solver for Day20 {
+    type Input = Vec<Pos>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        parse_track(input)
+    }
+
+    fn part1(&self, track: &Self::Input) -> Result<Answer, InputError> {
+        Ok(count_short_cuts(track, 2, self.saving).into())
+    }
+
+    fn part2(&self, track: &Self::Input) -> Result<Answer, InputError> {
+        Ok(count_short_cuts(track, 20, self.saving).into())
+    }
+}
@@ -1,3 +1,7 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::{Lines, ParseError};
 use std::collections::{HashMap, VecDeque};
 
 const NUMPAD: &str = "789
@@ -207,30 +211,42 @@ fn min_length(
     output
 }
 
-fn main() {
+fn complexity(codes: &[String], max_depth: u8) -> usize {
     let mut numpad = InputDevice::new(NUMPAD);
     let mut dirpad = InputDevice::new(DIRPAD);
 
-    let input = include_str!("../input.txt");
-    let part1 = input
-        .lines()
+    codes
+        .iter()
         .map(|s| {
             let number_part: String = s.chars().filter(|c| c.is_ascii_digit()).collect();
-            let min_length = propagate_presses(s, 2, &mut numpad, &mut dirpad);
+            let min_length = propagate_presses(s, max_depth, &mut numpad, &mut dirpad);
             min_length * number_part.parse::<usize>().unwrap()
         })
-        .sum::<usize>();
+        .sum::<usize>()
+}
 
-    dbg!(part1);
+pub struct Day21;
 
-    let part2 = input
-        .lines()
-        .map(|s| {
-            let number_part: String = s.chars().filter(|c| c.is_ascii_digit()).collect();
-            let min_length = propagate_presses(s, 25, &mut numpad, &mut dirpad);
-            min_length * number_part.parse::<usize>().unwrap()
-        })
-        .sum::<usize>();
+impl Solver for Day21 {
+    type Input = Vec<String>;
 
-    dbg!(part2);
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        let codes = Lines::new(input).map(|mut line| {
+            let code = line.rest().to_owned();
+            line.number::<usize>()?;
+            line.tag("A")?;
+            line.end()?;
+            Ok(code)
+        });
+
+        Ok(codes.collect::<Result<_, ParseError>>()?)
+    }
+
+    fn part1(&self, codes: &Self::Input) -> Result<Answer, InputError> {
+        Ok(complexity(codes, 2).into())
+    }
+
+    fn part2(&self, codes: &Self::Input) -> Result<Answer, InputError> {
+        Ok(complexity(codes, 25).into())
+    }
 }
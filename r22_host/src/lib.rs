@@ -1,3 +1,7 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::{Lines, ParseError};
 use rayon::prelude::*;
 use std::collections::VecDeque;
 
@@ -10,7 +14,7 @@ fn evolve(secret_num: isize) -> isize {
     ((step2 * 2048) ^ step2) % PRUNE
 }
 
-fn part2(input: &str) -> isize {
+fn part2(starting_secrets: &[isize]) -> isize {
     let mut patterns: Vec<[isize; 4]> = Vec::new();
     for diff1 in -9..=9 {
         for diff2 in -9..=9 {
@@ -31,8 +35,6 @@ fn part2(input: &str) -> isize {
             && (-9..=9isize).contains(&pattern.iter().skip(1).take(2).cloned().sum())
     });
 
-    let starting_secrets: Vec<isize> = input.lines().map(|s| s.parse().unwrap()).collect();
-
     patterns
         .par_iter()
         .map(|pattern| {
@@ -64,21 +66,37 @@ fn part2(input: &str) -> isize {
         .unwrap()
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+pub struct Day22;
 
-    let part1 = input
-        .lines()
-        .map(|s| {
-            let mut num = s.parse().unwrap();
-            for _ in 0..RUNLEN {
-                num = evolve(num);
-            }
-            num
-        })
-        .sum::<isize>();
+impl Solver for Day22 {
+    type Input = Vec<isize>;
 
-    dbg!(part1);
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        let secrets = Lines::new(input).map(|mut line| {
+            // secrets are never negative
+            let secret: u32 = line.number()?;
+            line.end()?;
+            Ok(secret as isize)
+        });
+
+        Ok(secrets.collect::<Result<_, ParseError>>()?)
+    }
 
-    dbg!(part2(input));
+    fn part1(&self, starting_secrets: &Self::Input) -> Result<Answer, InputError> {
+        Ok(starting_secrets
+            .iter()
+            .map(|&start| {
+                let mut num = start;
+                for _ in 0..RUNLEN {
+                    num = evolve(num);
+                }
+                num
+            })
+            .sum::<isize>()
+            .into())
+    }
+
+    fn part2(&self, starting_secrets: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part2(starting_secrets).into())
+    }
 }
@@ -1,3 +1,7 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::{self, Cursor, Lines, ParseError};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -6,27 +10,28 @@ struct Node {
 }
 
 impl Node {
-    fn new(input: &str) -> Self {
-        let mut chars = input.chars();
-        Self {
-            name: [chars.next().unwrap(), chars.next().unwrap()],
-        }
+    fn new(line: &mut Cursor) -> Result<Self, ParseError> {
+        let mut letter = || line.char(|chr| chr.is_ascii_lowercase(), "a computer name");
+        Ok(Self {
+            name: [letter()?, letter()?],
+        })
     }
 }
 
 #[derive(Debug)]
-struct Graph {
+pub struct Graph {
     edges: HashMap<Node, Vec<Node>>,
 }
 
 impl Graph {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, ParseError> {
         let mut edges = HashMap::new();
 
-        for line in input.lines() {
-            let (left, right) = line.split_once('-').unwrap();
-            let left_node = Node::new(left);
-            let right_node = Node::new(right);
+        for mut line in Lines::new(input) {
+            let left_node = Node::new(&mut line)?;
+            line.tag("-")?;
+            let right_node = Node::new(&mut line)?;
+            line.end()?;
 
             edges
                 .entry(left_node)
@@ -38,8 +43,11 @@ impl Graph {
                 .and_modify(|nodes: &mut Vec<Node>| nodes.push(left_node))
                 .or_insert(vec![left_node]);
         }
+        if edges.is_empty() {
+            return Err(parse::missing(input, "a connection"));
+        }
 
-        Graph { edges }
+        Ok(Graph { edges })
     }
 }
 
@@ -117,14 +125,51 @@ fn part2(graph: &Graph) -> String {
 
     longest
         .into_iter()
-        .cloned()
-        .flat_map(|n| vec![n.name[0], n.name[1], ','])
-        .collect()
+        .map(|n| n.name.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = Graph;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Ok(Graph::new(input)?)
+    }
+
+    fn part1(&self, graph: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part1(graph).into())
+    }
+
+    fn part2(&self, graph: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part2(graph).into())
+    }
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let graph = Graph::new(input);
-    dbg!(part1(&graph));
-    dbg!(part2(&graph));
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::parse::{Expected, Found};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(
+            Day23.parse("").err(),
+            Some(InputError::Parse(ParseError {
+                line: 1,
+                column: 1,
+                expected: Expected::Thing("a connection"),
+                found: Found::EndOfInput,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_one_connection() {
+        let graph = Day23.parse("ta-kh\n").unwrap();
+        assert_eq!(Day23.part1(&graph), Ok(0.into()));
+        assert_eq!(Day23.part2(&graph), Ok("kh,ta".into()));
+    }
 }
@@ -0,0 +1,275 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::{CapacityError, InputError, NoAnswer};
+use aoc_core::parse::{self, Cursor, Expected, Lines, ParseError};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    vars: HashMap<[char; 3], bool>,
+    code: Vec<(Op, [[char; 3]; 3])>,
+}
+
+impl Machine {
+    fn new(input: &str) -> Result<Self, InputError> {
+        let mut lines = Lines::new(input);
+
+        let vars: HashMap<_, _> = lines
+            .section()
+            .map(|mut line| {
+                let name = Self::read_name(&mut line)?;
+                line.tag(": ")?;
+                let value = line.char(|chr| chr == '0' || chr == '1', "0 or 1")?;
+                line.end()?;
+                Ok((name, value == '1'))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        // where each gate's inputs were read, to point at one nothing sets
+        let mut reads = Vec::new();
+        let mut code = lines
+            .map(|mut line| {
+                reads.push(line.clone());
+                let arg1 = Self::read_name(&mut line)?;
+                line.tag(" ")?;
+                let start = line.clone();
+                let op = match line.take_while(|chr| chr.is_ascii_uppercase()) {
+                    "AND" => Op::And,
+                    "OR" => Op::Or,
+                    "XOR" => Op::Xor,
+                    _ => return Err(start.error(Expected::Thing("AND, OR or XOR"))),
+                };
+                line.tag(" ")?;
+                reads.push(line.clone());
+                let arg2 = Self::read_name(&mut line)?;
+                line.tag(" -> ")?;
+                let arg3 = Self::read_name(&mut line)?;
+                line.end()?;
+                Ok((op, [arg1, arg2, arg3]))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let set: HashSet<_> = code.iter().map(|(_op, args)| args[2]).collect();
+        let args = code.iter().flat_map(|(_op, args)| &args[0..=1]);
+        if let Some((_arg, read)) = args
+            .zip(&reads)
+            .find(|(arg, _read)| !vars.contains_key(*arg) && !set.contains(*arg))
+        {
+            return Err(read.error(Expected::Thing("a wire that is set")).into());
+        }
+
+        let zs = vars
+            .keys()
+            .chain(&set)
+            .filter(|name| name[0] == 'z')
+            .collect::<HashSet<_>>()
+            .len();
+        if zs == 0 {
+            return Err(parse::missing(input, "a z wire").into());
+        }
+        if zs > usize::BITS as usize {
+            return Err(CapacityError {
+                buffer: "z wires",
+                capacity: usize::BITS as usize,
+                line: Lines::new(input).count(),
+            }
+            .into());
+        }
+
+        code.reverse();
+
+        Ok(Self { vars, code })
+    }
+
+    fn read_name(line: &mut Cursor) -> Result<[char; 3], ParseError> {
+        let mut chr = || line.char(|chr| chr.is_ascii_alphanumeric(), "a wire name");
+        Ok([chr()?, chr()?, chr()?])
+    }
+
+    /// Sets every wire, unless some gates wait on each other in a loop.
+    fn run(&mut self) -> Result<(), NoAnswer> {
+        let mut waiting = VecDeque::new();
+        // gates put back in a row without any other getting a value
+        let mut stuck = 0;
+
+        while !self.code.is_empty() || !waiting.is_empty() {
+            if self.code.is_empty() && stuck > waiting.len() {
+                return Err(NoAnswer {
+                    missing: "the gates wait on each other",
+                });
+            }
+            let (op, [arg1, arg2, dest]) = if self.code.is_empty() {
+                waiting.pop_front().unwrap()
+            } else {
+                self.code.pop().unwrap()
+            };
+
+            let val1 = self.vars.get(&arg1);
+            let val2 = self.vars.get(&arg2);
+
+            if let (Some(&val1), Some(&val2)) = (val1, val2) {
+                let answer = match op {
+                    Op::And => val1 & val2,
+                    Op::Or => val1 | val2,
+                    Op::Xor => val1 ^ val2,
+                };
+
+                self.vars
+                    .entry(dest)
+                    .and_modify(|v| *v = answer)
+                    .or_insert(answer);
+                stuck = 0;
+            } else {
+                waiting.push_back((op, [arg1, arg2, dest]));
+                stuck += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn output(&self) -> usize {
+        let mut zvars: Vec<_> = self.vars.iter().filter(|(k, _v)| k[0] == 'z').collect();
+        zvars.sort_by_key(|(k, _v)| **k);
+        log::debug!("z wires: {zvars:?}");
+        let str_output: String = zvars
+            .into_iter()
+            .rev()
+            .map(|(_k, v)| if *v { '1' } else { '0' })
+            .collect();
+
+        usize::from_str_radix(&str_output, 2).unwrap()
+    }
+}
+
+/// The wires whose gates don't sit where a ripple carry adder has them,
+/// which are the ones that were swapped.
+///
+/// Every bit after the first is `x XOR y -> sum`, `x AND y -> both`,
+/// `sum XOR carry -> z`, `sum AND carry -> carried` and
+/// `both OR carried -> carry out`, and bit 0 is a half adder. So only the
+/// XORs reading the carry write to z, except the top z which is the last
+/// carry out, `x XOR y` always goes on into an XOR, and every AND but bit
+/// 0's goes into an OR.
+fn part2(machine: &Machine) -> String {
+    let last = machine
+        .code
+        .iter()
+        .map(|(_op, args)| args[2])
+        .filter(|dest| dest[0] == 'z')
+        .max();
+    let feeds = |wire: [char; 3], into: Op| {
+        machine
+            .code
+            .iter()
+            .any(|(op, args)| *op == into && args[0..=1].contains(&wire))
+    };
+
+    let mut swaps = BTreeSet::new();
+    for (op, [arg1, _arg2, dest]) in &machine.code {
+        let from_inputs = matches!(arg1[0], 'x' | 'y');
+        let first_bit = from_inputs && arg1[1..] == ['0', '0'];
+        let to_z = dest[0] == 'z';
+        let wrong = match op {
+            Op::Xor if from_inputs => !first_bit && !feeds(*dest, Op::Xor),
+            Op::Xor => !to_z || Some(*dest) == last,
+            Op::And => !first_bit && !feeds(*dest, Op::Or),
+            Op::Or => to_z && Some(*dest) != last,
+        };
+        if wrong {
+            swaps.insert(*dest);
+        }
+    }
+
+    swaps
+        .into_iter()
+        .map(|dest| dest.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Machine;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        Machine::new(input)
+    }
+
+    fn part1(&self, machine: &Self::Input) -> Result<Answer, InputError> {
+        let mut machine = machine.clone();
+        machine.run()?;
+        Ok(machine.output().into())
+    }
+
+    fn part2(&self, machine: &Self::Input) -> Result<Answer, InputError> {
+        Ok(part2(machine).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::parse::Found;
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(
+            Day24.parse("").err(),
+            Some(InputError::Parse(ParseError {
+                line: 1,
+                column: 1,
+                expected: Expected::Thing("a z wire"),
+                found: Found::EndOfInput,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_no_z_wires() {
+        let input = "x00: 1\ny00: 0\n\nx00 AND y00 -> a00\n";
+        assert_eq!(
+            Day24.parse(input).err(),
+            Some(InputError::Parse(ParseError {
+                line: 5,
+                column: 1,
+                expected: Expected::Thing("a z wire"),
+                found: Found::EndOfInput,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_unset_wire() {
+        let input = "x00: 1\n\nx00 AND y00 -> z00\n";
+        assert_eq!(
+            Day24.parse(input).err(),
+            Some(InputError::Parse(ParseError {
+                line: 3,
+                column: 9,
+                expected: Expected::Thing("a wire that is set"),
+                found: Found::Char('y'),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_loop() {
+        let input = "x00: 1\n\nx00 AND b00 -> a00\nx00 AND a00 -> b00\na00 OR b00 -> z00\n";
+        let machine = Day24.parse(input).unwrap();
+        assert_eq!(
+            Day24.part1(&machine),
+            Err(InputError::NoAnswer(NoAnswer {
+                missing: "the gates wait on each other",
+            }))
+        );
+    }
+}
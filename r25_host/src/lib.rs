@@ -0,0 +1,89 @@
+use aoc_core::Solver;
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::parse::{Expected, Lines, ParseError};
+
+const PINCOUNT: usize = 5;
+const ROWMAX: u8 = 6;
+const ROWS: usize = ROWMAX as usize + 1;
+
+#[derive(Debug)]
+pub enum LockNKey {
+    Lock([u8; PINCOUNT]),
+    Key([u8; PINCOUNT]),
+}
+
+fn parse_lock_or_key(lines: &mut Lines) -> Result<LockNKey, ParseError> {
+    let mut rows = [[false; PINCOUNT]; ROWS];
+    let mut row_starts = Vec::with_capacity(ROWS);
+    for row in rows.iter_mut() {
+        let mut line = lines.expect("a row of pins")?;
+        row_starts.push(line.clone());
+        for pin in row.iter_mut() {
+            *pin = line.char(|chr| chr == '#' || chr == '.', "'#' or '.'")? == '#';
+        }
+        line.end()?;
+    }
+
+    // locks hang down from a solid top row, keys stand up on a solid bottom one
+    let solid = |row: &[bool; PINCOUNT]| row.iter().all(|&pin| pin);
+    let empty = |row: &[bool; PINCOUNT]| row.iter().all(|&pin| !pin);
+    let lock_pin = |col: usize| rows.iter().take_while(|row| row[col]).count() as u8 - 1;
+    let key_pin = |col: usize| rows.iter().rev().take_while(|row| row[col]).count() as u8 - 1;
+
+    if solid(&rows[0]) && empty(&rows[ROWS - 1]) {
+        Ok(LockNKey::Lock(std::array::from_fn(lock_pin)))
+    } else if empty(&rows[0]) && solid(&rows[ROWS - 1]) {
+        Ok(LockNKey::Key(std::array::from_fn(key_pin)))
+    } else if solid(&rows[0]) || empty(&rows[0]) {
+        Err(row_starts[ROWS - 1].error(Expected::Thing("the other end of a lock or key")))
+    } else {
+        Err(row_starts[0].error(Expected::Thing("the top of a lock or key")))
+    }
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Vec<LockNKey>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, InputError> {
+        let mut lines = Lines::new(input);
+        let mut locksnkeys = Vec::new();
+
+        loop {
+            locksnkeys.push(parse_lock_or_key(&mut lines)?);
+            // locks and keys are separated by a blank line
+            match lines.next() {
+                Some(line) => line.end()?,
+                None => break,
+            }
+        }
+
+        Ok(locksnkeys)
+    }
+
+    fn part1(&self, locksnkeys: &Self::Input) -> Result<Answer, InputError> {
+        let mut matches = 0;
+
+        for lk1 in locksnkeys.iter() {
+            for lk2 in locksnkeys.iter() {
+                if let (LockNKey::Lock(lockpins), LockNKey::Key(keypins)) = (lk1, lk2)
+                    && lockpins
+                        .iter()
+                        .zip(keypins)
+                        .all(|(p1, p2)| p1 + p2 < ROWMAX)
+                {
+                    matches += 1;
+                }
+            }
+        }
+
+        Ok(matches.into())
+    }
+
+    fn part2(&self, _locksnkeys: &Self::Input) -> Result<Answer, InputError> {
+        // day 25 only has the one puzzle
+        Ok(Answer::Text(String::new()))
+    }
+}
@@ -0,0 +1,5 @@
+/* mps2-an385: Cortex-M3 with 4M of SSRAM each for code and data */
+MEMORY {
+    FLASH : ORIGIN = 0x00000000, LENGTH = 4M
+    RAM   : ORIGIN = 0x20000000, LENGTH = 4M
+}
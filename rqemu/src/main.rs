@@ -0,0 +1,121 @@
+//! The firmware solvers on a Cortex-M3 QEMU can emulate, so the no_std code
+//! can be run without a Pico. Takes `<day> <input path>` on the semihosting
+//! command line and prints the answers on semihosting stdout in the same
+//! `AOC ...` format the Pico logs through defmt. The time in it is the
+//! host's, so it says how fast QEMU is rather than how fast a Cortex-M3 is.
+
+#![no_std]
+#![no_main]
+
+use aoc_firmware::solve_timed;
+use cortex_m_rt::entry;
+use cortex_m_semihosting::debug::{self, ExitStatus};
+use cortex_m_semihosting::{hprintln, nr, syscall};
+use panic_semihosting as _;
+
+const MAX_INPUT: usize = 32 * 1024;
+const MAX_PATH: usize = 256;
+
+fn cmdline(buf: &mut [u8]) -> Option<&str> {
+    // GET_CMDLINE fills in the buffer and writes the length back into the block
+    let mut block = [buf.as_mut_ptr() as usize, buf.len()];
+    let failed = unsafe { syscall!(GET_CMDLINE, block.as_mut_ptr()) };
+    if failed != 0 {
+        return None;
+    }
+
+    core::str::from_utf8(&buf[..block[1]]).ok()
+}
+
+/// The semihosting tick rate, or `None` when the host doesn't say.
+fn tick_freq() -> Option<u64> {
+    let freq = unsafe { syscall!(TICKFREQ) } as isize;
+    (freq > 0).then_some(freq as u64)
+}
+
+/// Microseconds since the program started, by the host's clock.
+fn now_us(freq: u64) -> u64 {
+    // ELAPSED writes the 64-bit tick count as two words, low one first
+    let mut ticks = [0usize; 2];
+    if unsafe { syscall!(ELAPSED, ticks.as_mut_ptr()) } != 0 {
+        return 0;
+    }
+    let ticks = ticks[0] as u64 | (ticks[1] as u64) << 32;
+    (ticks as u128 * 1_000_000 / freq as u128) as u64
+}
+
+fn read_file<'a>(path: &str, buf: &'a mut [u8]) -> Option<&'a [u8]> {
+    let mut name = [0u8; MAX_PATH];
+    if path.len() >= MAX_PATH {
+        return None;
+    }
+    name[..path.len()].copy_from_slice(path.as_bytes());
+
+    let fd = unsafe { syscall!(OPEN, name.as_ptr(), nr::open::R_BINARY, path.len()) } as isize;
+    if fd < 0 {
+        return None;
+    }
+
+    let len = unsafe { syscall!(FLEN, fd) };
+    let unread = if len <= buf.len() {
+        unsafe { syscall!(READ, fd, buf.as_mut_ptr(), len) }
+    } else {
+        len
+    };
+    unsafe { syscall!(CLOSE, fd) };
+
+    (unread == 0).then(|| &buf[..len])
+}
+
+fn run(args: &mut [u8], input: &mut [u8]) -> ExitStatus {
+    let Some(cmdline) = cmdline(args) else {
+        hprintln!("could not read the command line");
+        return debug::EXIT_FAILURE;
+    };
+
+    // the first word is the program name
+    let mut words = cmdline.split_whitespace().skip(1);
+    let day = words.next().and_then(|day| day.parse::<u8>().ok());
+    let (Some(day), Some(path)) = (day, words.next()) else {
+        hprintln!("usage: rqemu <day> <input path>");
+        return debug::EXIT_FAILURE;
+    };
+
+    let Some(input) = read_file(path, input) else {
+        hprintln!("could not read {} (at most {} bytes)", path, MAX_INPUT);
+        return debug::EXIT_FAILURE;
+    };
+    let Some(freq) = tick_freq() else {
+        hprintln!("the host has no semihosting clock");
+        return debug::EXIT_FAILURE;
+    };
+    match solve_timed(day, input, &|| now_us(freq)) {
+        Some(Ok((ans1, ans2, timings))) => {
+            hprintln!(
+                "AOC day={} part1={} part2={} elapsed_us={}",
+                day,
+                ans1,
+                ans2,
+                timings.total_us()
+            );
+            debug::EXIT_SUCCESS
+        }
+        Some(Err(err)) => {
+            hprintln!("day {}: {}", day, err);
+            debug::EXIT_FAILURE
+        }
+        None => {
+            hprintln!("no solution for day {}", day);
+            debug::EXIT_FAILURE
+        }
+    }
+}
+
+#[entry]
+fn main() -> ! {
+    let args = cortex_m::singleton!(: [u8; MAX_PATH + 16] = [0; MAX_PATH + 16]).unwrap();
+    let input = cortex_m::singleton!(: [u8; MAX_INPUT] = [0; MAX_INPUT]).unwrap();
+
+    debug::exit(run(args, input));
+    loop {}
+}
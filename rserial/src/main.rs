@@ -0,0 +1,153 @@
+#![no_std]
+#![no_main]
+
+use bsp::entry;
+use bsp::hal::{self, pac};
+use defmt::*;
+use defmt_rtt as _;
+use panic_probe as _;
+use rp_pico as bsp;
+
+use aoc_core::protocol::{Decoder, FrameError, Header, Kind};
+use aoc_core::Part;
+use aoc_firmware::{solve, text};
+use cortex_m::singleton;
+use usb_device::class_prelude::*;
+use usb_device::prelude::*;
+use usbd_serial::SerialPort;
+
+/// Biggest puzzle input that can be uploaded, the largest so far is day 4 at ~20K
+const MAX_INPUT: usize = 32 * 1024;
+
+fn write_all(
+    usb_dev: &mut UsbDevice<hal::usb::UsbBus>,
+    serial: &mut SerialPort<hal::usb::UsbBus>,
+    mut bytes: &[u8],
+) {
+    while !bytes.is_empty() {
+        usb_dev.poll(&mut [serial]);
+        match serial.write(bytes) {
+            Ok(count) => bytes = &bytes[count..],
+            Err(UsbError::WouldBlock) => {}
+            // the host went away, nobody is left to read the rest
+            Err(_) => return,
+        }
+    }
+}
+
+fn send(
+    usb_dev: &mut UsbDevice<hal::usb::UsbBus>,
+    serial: &mut SerialPort<hal::usb::UsbBus>,
+    kind: Kind,
+    day: u8,
+    part: Option<Part>,
+    payload: &str,
+) {
+    let header = Header::new(kind, day, part, payload.as_bytes());
+    write_all(usb_dev, serial, &header.encode());
+    write_all(usb_dev, serial, payload.as_bytes());
+}
+
+#[entry]
+fn main() -> ! {
+    info!("Program start");
+
+    let mut pac = pac::Peripherals::take().unwrap();
+    let mut watchdog = hal::Watchdog::new(pac.WATCHDOG);
+    let clocks = hal::clocks::init_clocks_and_plls(
+        bsp::XOSC_CRYSTAL_FREQ,
+        pac.XOSC,
+        pac.CLOCKS,
+        pac.PLL_SYS,
+        pac.PLL_USB,
+        &mut pac.RESETS,
+        &mut watchdog,
+    )
+    .ok()
+    .unwrap();
+
+    let usb_bus = UsbBusAllocator::new(hal::usb::UsbBus::new(
+        pac.USBCTRL_REGS,
+        pac.USBCTRL_DPRAM,
+        clocks.usb_clock,
+        true,
+        &mut pac.RESETS,
+    ));
+    let mut serial = SerialPort::new(&usb_bus);
+    let mut usb_dev = UsbDeviceBuilder::new(&usb_bus, UsbVidPid(0x16c0, 0x27dd))
+        .strings(&[StringDescriptors::default()
+            .manufacturer("adventofcode2024")
+            .product("aoc solver")
+            .serial_number("AOC2024")])
+        .unwrap()
+        .device_class(usbd_serial::USB_CLASS_CDC)
+        .build();
+
+    let input_buf = singleton!(: [u8; MAX_INPUT] = [0; MAX_INPUT]).unwrap();
+    let mut decoder = Decoder::new(input_buf);
+    let mut chunk = [0u8; 64];
+
+    info!("waiting for input");
+    loop {
+        if !usb_dev.poll(&mut [&mut serial]) {
+            continue;
+        }
+
+        let count = match serial.read(&mut chunk) {
+            Ok(count) => count,
+            Err(_) => continue,
+        };
+
+        for &byte in &chunk[..count] {
+            let header = match decoder.push(byte) {
+                Ok(Some(header)) => header,
+                Ok(None) => continue,
+                // stray bytes between frames, e.g. a terminal poking the port
+                Err(FrameError::UnknownKind(_)) => continue,
+                Err(err) => {
+                    warn!("dropped frame: {}", err);
+                    send(&mut usb_dev, &mut serial, Kind::Error, 0, None, &text(err));
+                    continue;
+                }
+            };
+
+            if header.kind != Kind::Input {
+                warn!("ignoring {} frame", header.kind);
+                continue;
+            }
+
+            // USB isn't polled while solving, the host just sees a slow reply
+            info!("solving day {}", header.day);
+            let result = solve(header.day, decoder.payload());
+            info!("calculation finished");
+
+            match result {
+                Ok((ans1, ans2)) => {
+                    for (part, answer) in [(Part::One, ans1), (Part::Two, ans2)] {
+                        if header.part.is_none_or(|asked| asked == part) {
+                            send(
+                                &mut usb_dev,
+                                &mut serial,
+                                Kind::Answer,
+                                header.day,
+                                Some(part),
+                                &answer,
+                            );
+                        }
+                    }
+                }
+                Err(message) => {
+                    error!("day {}: {}", header.day, message.as_str());
+                    send(
+                        &mut usb_dev,
+                        &mut serial,
+                        Kind::Error,
+                        header.day,
+                        None,
+                        &message,
+                    );
+                }
+            }
+        }
+    }
+}